@@ -0,0 +1,63 @@
+use std::hint::black_box;
+use std::time::Instant;
+
+use spectrum::cryptography::aes::{AESBackend, InitKey, AES, CTR};
+use spectrum::cryptography::CryptographicAlgorithm;
+
+const MEGABYTES: usize = 4;
+
+fn bench(name: &str, key: &[u8], backend: AESBackend) {
+    let aes = AES::with_backend(InitKey::from_slice(key).unwrap(), backend);
+    let message = vec![0x5a; MEGABYTES * 1024 * 1024];
+
+    let start = Instant::now();
+    let cipher = aes.encrypt(black_box(&message));
+    let encryption = start.elapsed();
+
+    let start = Instant::now();
+    black_box(aes.decrypt(black_box(&cipher)));
+    let decryption = start.elapsed();
+
+    println!(
+        "{name:<8} {:<12} ECB encrypt {:>8.2} MB/s  decrypt {:>8.2} MB/s",
+        format!("{backend:?}"),
+        MEGABYTES as f64 / encryption.as_secs_f64(),
+        MEGABYTES as f64 / decryption.as_secs_f64(),
+    );
+}
+
+fn bench_ctr(name: &str, key: &[u8], backend: AESBackend) {
+    let aes = AES::with_backend(InitKey::from_slice(key).unwrap(), backend);
+    let ctr = CTR::new(aes, [0; 16]);
+    let message = vec![0x5a; MEGABYTES * 1024 * 1024];
+
+    let start = Instant::now();
+    black_box(ctr.encrypt(black_box(&message)).unwrap());
+    let encryption = start.elapsed();
+
+    println!(
+        "{name:<8} {:<12} CTR encrypt {:>8.2} MB/s",
+        format!("{backend:?}"),
+        MEGABYTES as f64 / encryption.as_secs_f64(),
+    );
+}
+
+fn main() {
+    for (name, key) in [
+        ("AES-128", vec![0x01; 16]),
+        ("AES-192", vec![0x01; 24]),
+        ("AES-256", vec![0x01; 32]),
+    ] {
+        for backend in [
+            AESBackend::Reference,
+            AESBackend::TTable,
+            AESBackend::ConstantTime,
+            AESBackend::AESNI,
+        ] {
+            if backend.is_supported() {
+                bench(name, &key, backend);
+                bench_ctr(name, &key, backend);
+            }
+        }
+    }
+}
@@ -1,6 +1,10 @@
 pub mod aes;
+pub mod hkdf;
+pub mod hmac;
+pub mod pbkdf2;
 pub mod rsa;
 pub mod sha;
+pub mod sha3;
 
 use std::string::FromUtf8Error;
 
@@ -48,6 +52,15 @@ pub fn decrypt_bytes(
 
 pub trait HashingAlgorithm {
     fn hash(&self, message: Vec<u8>) -> Vec<u8>;
+    fn block_size(&self) -> usize;
+    fn output_size(&self) -> usize;
+}
+
+// `finalize` returns the digest and leaves the hasher reset, ready for a new message
+pub trait StreamingHashingAlgorithm {
+    fn update(&mut self, data: &[u8]);
+    fn finalize(&mut self) -> Vec<u8>;
+    fn reset(&mut self);
 }
 
 pub fn hash(hash: &impl HashingAlgorithm, message: String) -> String {
@@ -55,3 +68,16 @@ pub fn hash(hash: &impl HashingAlgorithm, message: String) -> String {
     message = hash.hash(message);
     u8_to_hex(message)
 }
+
+pub fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    if a.len() != b.len() {
+        return false;
+    }
+
+    let mut difference = 0;
+    for (x, y) in a.iter().zip(b) {
+        difference |= x ^ y;
+    }
+
+    std::hint::black_box(difference) == 0
+}
@@ -1,28 +1,115 @@
 extern crate rand;
 
 use crate::cryptography::CryptographicAlgorithm;
-use crate::format::vec_to_array16;
+use crate::format::{vec_to_array16, vec_to_array24, vec_to_array32};
 use crate::math::finite_field_multiplication;
 
 mod aes128;
 mod aes192;
 mod aes256;
+mod aesni;
+mod cbc;
+mod ccm;
+mod cfb;
+mod cmac;
+mod constant_time;
+mod ctr;
+mod gcm;
+mod gcm_siv;
+mod key_wrap;
+mod ofb;
+mod ttable;
+mod xts;
 
 use aes128::AES128;
 use aes192::AES192;
 use aes256::AES256;
 
+pub use cbc::CBC;
+pub use ccm::CCM;
+pub use cfb::{CFBType, CFB};
+pub use cmac::CMAC;
+pub use ctr::CTR;
+pub use gcm::{GCM, GMAC};
+pub use gcm_siv::GCMSIV;
+pub use key_wrap::KeyWrap;
+pub use ofb::OFB;
+pub use xts::XTS;
+
 #[derive(Debug)]
 pub enum AESError {
-    InccorectSize
+    InccorectSize,
+    InvalidLength,
+    InvalidPadding,
+    AuthenticationFailed,
+    InvalidKey,
+}
+
+// Block cipher implementation, the results are identical but the speed is not.
+// Only ConstantTime and AESNI avoid table lookups indexed by secret data.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AESBackend {
+    Reference,
+    TTable,
+    ConstantTime,
+    AESNI,
+}
+
+impl AESBackend {
+    // AES-NI when the CPU has it, the portable reference code otherwise
+    pub fn detect() -> Self {
+        if aesni::is_supported() {
+            AESBackend::AESNI
+        } else {
+            AESBackend::Reference
+        }
+    }
+
+    pub fn is_supported(&self) -> bool {
+        match self {
+            AESBackend::AESNI => aesni::is_supported(),
+            _ => true,
+        }
+    }
+
+    // Unsupported backends fall back to the detected one
+    fn or_supported(self) -> Self {
+        if self.is_supported() {
+            self
+        } else {
+            AESBackend::detect()
+        }
+    }
 }
 
+// Number of blocks the pipelined backends work on at once
+const PARALLEL_BLOCKS: usize = 8;
+
 pub enum InitKey {
     AES128([u8; 16]),
     AES192([u8; 24]),
     AES256([u8; 32])
 }
 
+impl InitKey {
+    pub fn from_slice(bytes: &[u8]) -> Result<Self, AESError> {
+        match bytes.len() {
+            16 => Ok(InitKey::AES128(vec_to_array16(bytes.to_vec()).unwrap())),
+            24 => Ok(InitKey::AES192(vec_to_array24(bytes.to_vec()).unwrap())),
+            32 => Ok(InitKey::AES256(vec_to_array32(bytes.to_vec()).unwrap())),
+            _ => Err(AESError::InccorectSize),
+        }
+    }
+
+    pub fn as_bytes(&self) -> &[u8] {
+        match self {
+            InitKey::AES128(init_key) => init_key,
+            InitKey::AES192(init_key) => init_key,
+            InitKey::AES256(init_key) => init_key,
+        }
+    }
+}
+
 pub enum AES {
     AES128(AES128),
     AES192(AES192),
@@ -48,6 +135,14 @@ impl AES {
         }
     }
 
+    pub fn with_backend(init_key: InitKey, backend: AESBackend) -> Self {
+        match init_key {
+            InitKey::AES128(init_key) => AES::AES128(AES128::with_backend(init_key, backend)),
+            InitKey::AES192(init_key) => AES::AES192(AES192::with_backend(init_key, backend)),
+            InitKey::AES256(init_key) => AES::AES256(AES256::with_backend(init_key, backend)),
+        }
+    }
+
     pub fn from_hex(hex: &str) -> Result<Self, AESError> {
         Ok(AES::AES256(AES256::from_hex(hex)?))
     }
@@ -59,6 +154,47 @@ impl AES {
             AES::AES256(aes) => InitKey::AES256(aes.init_key) 
         }
     }
+
+    pub fn backend(&self) -> AESBackend {
+        match self {
+            AES::AES128(aes) => aes.backend(),
+            AES::AES192(aes) => aes.backend(),
+            AES::AES256(aes) => aes.backend(),
+        }
+    }
+
+    pub fn encrypt_block(&self, matrix: &mut [u8; 16]) {
+        match self {
+            Self::AES128(aes) => aes.encryption_algorithm(matrix),
+            Self::AES192(aes) => aes.encryption_algorithm(matrix),
+            Self::AES256(aes) => aes.encryption_algorithm(matrix),
+        }
+    }
+
+    pub fn decrypt_block(&self, matrix: &mut [u8; 16]) {
+        match self {
+            Self::AES128(aes) => aes.decryption_algorithm(matrix),
+            Self::AES192(aes) => aes.decryption_algorithm(matrix),
+            Self::AES256(aes) => aes.decryption_algorithm(matrix),
+        }
+    }
+
+    // Same as calling encrypt_block on each block, but lets AES-NI pipeline them
+    pub fn encrypt_blocks(&self, blocks: &mut [[u8; 16]]) {
+        match self {
+            Self::AES128(aes) => aes.encrypt_blocks(blocks),
+            Self::AES192(aes) => aes.encrypt_blocks(blocks),
+            Self::AES256(aes) => aes.encrypt_blocks(blocks),
+        }
+    }
+
+    pub fn decrypt_blocks(&self, blocks: &mut [[u8; 16]]) {
+        match self {
+            Self::AES128(aes) => aes.decrypt_blocks(blocks),
+            Self::AES192(aes) => aes.decrypt_blocks(blocks),
+            Self::AES256(aes) => aes.decrypt_blocks(blocks),
+        }
+    }
 }
 
 impl CryptographicAlgorithm for AES {
@@ -77,11 +213,7 @@ impl CryptographicAlgorithm for AES {
 
             let mut matrix = vec_to_array16(vec).unwrap();
 
-            match self {
-                Self::AES128(aes) => aes.encryption_algorithm(&mut matrix),
-                Self::AES192(aes) => aes.encryption_algorithm(&mut matrix),
-                Self::AES256(aes) => aes.encryption_algorithm(&mut matrix),
-            }
+            self.encrypt_block(&mut matrix);
 
             cipher.append(&mut matrix.to_vec());
         }
@@ -104,11 +236,7 @@ impl CryptographicAlgorithm for AES {
 
             let mut matrix = vec_to_array16(vec).unwrap();
 
-            match self {
-                Self::AES128(aes) => aes.decryption_algorithm(&mut matrix),
-                Self::AES192(aes) => aes.decryption_algorithm(&mut matrix),
-                Self::AES256(aes) => aes.decryption_algorithm(&mut matrix),
-            }
+            self.decrypt_block(&mut matrix);
 
             message.append(&mut matrix.to_vec());
         }
@@ -149,9 +277,8 @@ fn rcon(mut byte: u8) -> u8 {
 
 fn schedule_core(bytes: &mut [u8; 4], i: u8) {
     rotate(bytes);
-    for byte in bytes.iter_mut() {
-        sbox(byte);
-    }
+    // Key bytes never index SBOX, this and the extra AES-256 step use sub_word instead
+    *bytes = constant_time::sub_word(u32::from_be_bytes(*bytes)).to_be_bytes();
     bytes[0] ^= rcon(i);
 }
 
@@ -164,30 +291,34 @@ fn add_key(matrix: &mut [u8; 16], key: &[u8]) {
 }
 
 // Encryption
+const SBOX: [u8; 256] = [
+    0x63, 0x7c, 0x77, 0x7b, 0xf2, 0x6b, 0x6f, 0xc5, 0x30, 0x01, 0x67, 0x2b, 0xfe, 0xd7,
+    0xab, 0x76, 0xca, 0x82, 0xc9, 0x7d, 0xfa, 0x59, 0x47, 0xf0, 0xad, 0xd4, 0xa2, 0xaf,
+    0x9c, 0xa4, 0x72, 0xc0, 0xb7, 0xfd, 0x93, 0x26, 0x36, 0x3f, 0xf7, 0xcc, 0x34, 0xa5,
+    0xe5, 0xf1, 0x71, 0xd8, 0x31, 0x15, 0x04, 0xc7, 0x23, 0xc3, 0x18, 0x96, 0x05, 0x9a,
+    0x07, 0x12, 0x80, 0xe2, 0xeb, 0x27, 0xb2, 0x75, 0x09, 0x83, 0x2c, 0x1a, 0x1b, 0x6e,
+    0x5a, 0xa0, 0x52, 0x3b, 0xd6, 0xb3, 0x29, 0xe3, 0x2f, 0x84, 0x53, 0xd1, 0x00, 0xed,
+    0x20, 0xfc, 0xb1, 0x5b, 0x6a, 0xcb, 0xbe, 0x39, 0x4a, 0x4c, 0x58, 0xcf, 0xd0, 0xef,
+    0xaa, 0xfb, 0x43, 0x4d, 0x33, 0x85, 0x45, 0xf9, 0x02, 0x7f, 0x50, 0x3c, 0x9f, 0xa8,
+    0x51, 0xa3, 0x40, 0x8f, 0x92, 0x9d, 0x38, 0xf5, 0xbc, 0xb6, 0xda, 0x21, 0x10, 0xff,
+    0xf3, 0xd2, 0xcd, 0x0c, 0x13, 0xec, 0x5f, 0x97, 0x44, 0x17, 0xc4, 0xa7, 0x7e, 0x3d,
+    0x64, 0x5d, 0x19, 0x73, 0x60, 0x81, 0x4f, 0xdc, 0x22, 0x2a, 0x90, 0x88, 0x46, 0xee,
+    0xb8, 0x14, 0xde, 0x5e, 0x0b, 0xdb, 0xe0, 0x32, 0x3a, 0x0a, 0x49, 0x06, 0x24, 0x5c,
+    0xc2, 0xd3, 0xac, 0x62, 0x91, 0x95, 0xe4, 0x79, 0xe7, 0xc8, 0x37, 0x6d, 0x8d, 0xd5,
+    0x4e, 0xa9, 0x6c, 0x56, 0xf4, 0xea, 0x65, 0x7a, 0xae, 0x08, 0xba, 0x78, 0x25, 0x2e,
+    0x1c, 0xa6, 0xb4, 0xc6, 0xe8, 0xdd, 0x74, 0x1f, 0x4b, 0xbd, 0x8b, 0x8a, 0x70, 0x3e,
+    0xb5, 0x66, 0x48, 0x03, 0xf6, 0x0e, 0x61, 0x35, 0x57, 0xb9, 0x86, 0xc1, 0x1d, 0x9e,
+    0xe1, 0xf8, 0x98, 0x11, 0x69, 0xd9, 0x8e, 0x94, 0x9b, 0x1e, 0x87, 0xe9, 0xce, 0x55,
+    0x28, 0xdf, 0x8c, 0xa1, 0x89, 0x0d, 0xbf, 0xe6, 0x42, 0x68, 0x41, 0x99, 0x2d, 0x0f,
+    0xb0, 0x54, 0xbb, 0x16,
+];
+
+// Only the Reference rounds call sbox and reverse_sbox on state bytes. ttable reads
+// SBOX and INVERSE_SBOX by public index to build its tables, and the key expansion of
+// every backend goes through constant_time::sub_word, so ConstantTime never indexes
+// either table with key or state data.
 fn sbox(byte: &mut u8) {
-    let sbox: [u8; 256] = [
-        0x63, 0x7c, 0x77, 0x7b, 0xf2, 0x6b, 0x6f, 0xc5, 0x30, 0x01, 0x67, 0x2b, 0xfe, 0xd7,
-        0xab, 0x76, 0xca, 0x82, 0xc9, 0x7d, 0xfa, 0x59, 0x47, 0xf0, 0xad, 0xd4, 0xa2, 0xaf,
-        0x9c, 0xa4, 0x72, 0xc0, 0xb7, 0xfd, 0x93, 0x26, 0x36, 0x3f, 0xf7, 0xcc, 0x34, 0xa5,
-        0xe5, 0xf1, 0x71, 0xd8, 0x31, 0x15, 0x04, 0xc7, 0x23, 0xc3, 0x18, 0x96, 0x05, 0x9a,
-        0x07, 0x12, 0x80, 0xe2, 0xeb, 0x27, 0xb2, 0x75, 0x09, 0x83, 0x2c, 0x1a, 0x1b, 0x6e,
-        0x5a, 0xa0, 0x52, 0x3b, 0xd6, 0xb3, 0x29, 0xe3, 0x2f, 0x84, 0x53, 0xd1, 0x00, 0xed,
-        0x20, 0xfc, 0xb1, 0x5b, 0x6a, 0xcb, 0xbe, 0x39, 0x4a, 0x4c, 0x58, 0xcf, 0xd0, 0xef,
-        0xaa, 0xfb, 0x43, 0x4d, 0x33, 0x85, 0x45, 0xf9, 0x02, 0x7f, 0x50, 0x3c, 0x9f, 0xa8,
-        0x51, 0xa3, 0x40, 0x8f, 0x92, 0x9d, 0x38, 0xf5, 0xbc, 0xb6, 0xda, 0x21, 0x10, 0xff,
-        0xf3, 0xd2, 0xcd, 0x0c, 0x13, 0xec, 0x5f, 0x97, 0x44, 0x17, 0xc4, 0xa7, 0x7e, 0x3d,
-        0x64, 0x5d, 0x19, 0x73, 0x60, 0x81, 0x4f, 0xdc, 0x22, 0x2a, 0x90, 0x88, 0x46, 0xee,
-        0xb8, 0x14, 0xde, 0x5e, 0x0b, 0xdb, 0xe0, 0x32, 0x3a, 0x0a, 0x49, 0x06, 0x24, 0x5c,
-        0xc2, 0xd3, 0xac, 0x62, 0x91, 0x95, 0xe4, 0x79, 0xe7, 0xc8, 0x37, 0x6d, 0x8d, 0xd5,
-        0x4e, 0xa9, 0x6c, 0x56, 0xf4, 0xea, 0x65, 0x7a, 0xae, 0x08, 0xba, 0x78, 0x25, 0x2e,
-        0x1c, 0xa6, 0xb4, 0xc6, 0xe8, 0xdd, 0x74, 0x1f, 0x4b, 0xbd, 0x8b, 0x8a, 0x70, 0x3e,
-        0xb5, 0x66, 0x48, 0x03, 0xf6, 0x0e, 0x61, 0x35, 0x57, 0xb9, 0x86, 0xc1, 0x1d, 0x9e,
-        0xe1, 0xf8, 0x98, 0x11, 0x69, 0xd9, 0x8e, 0x94, 0x9b, 0x1e, 0x87, 0xe9, 0xce, 0x55,
-        0x28, 0xdf, 0x8c, 0xa1, 0x89, 0x0d, 0xbf, 0xe6, 0x42, 0x68, 0x41, 0x99, 0x2d, 0x0f,
-        0xb0, 0x54, 0xbb, 0x16,
-    ];
-
-    *byte = sbox[*byte as usize];
+    *byte = SBOX[*byte as usize];
 }
 
 fn shift_rows(matrix: &mut [u8; 16]) {
@@ -245,30 +376,30 @@ fn mix_columns(matrix: &mut [u8]) {
 }
 
 // Decryption
+const INVERSE_SBOX: [u8; 256] = [
+    0x52, 0x09, 0x6a, 0xd5, 0x30, 0x36, 0xa5, 0x38, 0xbf, 0x40, 0xa3, 0x9e, 0x81, 0xf3,
+    0xd7, 0xfb, 0x7c, 0xe3, 0x39, 0x82, 0x9b, 0x2f, 0xff, 0x87, 0x34, 0x8e, 0x43, 0x44,
+    0xc4, 0xde, 0xe9, 0xcb, 0x54, 0x7b, 0x94, 0x32, 0xa6, 0xc2, 0x23, 0x3d, 0xee, 0x4c,
+    0x95, 0x0b, 0x42, 0xfa, 0xc3, 0x4e, 0x08, 0x2e, 0xa1, 0x66, 0x28, 0xd9, 0x24, 0xb2,
+    0x76, 0x5b, 0xa2, 0x49, 0x6d, 0x8b, 0xd1, 0x25, 0x72, 0xf8, 0xf6, 0x64, 0x86, 0x68,
+    0x98, 0x16, 0xd4, 0xa4, 0x5c, 0xcc, 0x5d, 0x65, 0xb6, 0x92, 0x6c, 0x70, 0x48, 0x50,
+    0xfd, 0xed, 0xb9, 0xda, 0x5e, 0x15, 0x46, 0x57, 0xa7, 0x8d, 0x9d, 0x84, 0x90, 0xd8,
+    0xab, 0x00, 0x8c, 0xbc, 0xd3, 0x0a, 0xf7, 0xe4, 0x58, 0x05, 0xb8, 0xb3, 0x45, 0x06,
+    0xd0, 0x2c, 0x1e, 0x8f, 0xca, 0x3f, 0x0f, 0x02, 0xc1, 0xaf, 0xbd, 0x03, 0x01, 0x13,
+    0x8a, 0x6b, 0x3a, 0x91, 0x11, 0x41, 0x4f, 0x67, 0xdc, 0xea, 0x97, 0xf2, 0xcf, 0xce,
+    0xf0, 0xb4, 0xe6, 0x73, 0x96, 0xac, 0x74, 0x22, 0xe7, 0xad, 0x35, 0x85, 0xe2, 0xf9,
+    0x37, 0xe8, 0x1c, 0x75, 0xdf, 0x6e, 0x47, 0xf1, 0x1a, 0x71, 0x1d, 0x29, 0xc5, 0x89,
+    0x6f, 0xb7, 0x62, 0x0e, 0xaa, 0x18, 0xbe, 0x1b, 0xfc, 0x56, 0x3e, 0x4b, 0xc6, 0xd2,
+    0x79, 0x20, 0x9a, 0xdb, 0xc0, 0xfe, 0x78, 0xcd, 0x5a, 0xf4, 0x1f, 0xdd, 0xa8, 0x33,
+    0x88, 0x07, 0xc7, 0x31, 0xb1, 0x12, 0x10, 0x59, 0x27, 0x80, 0xec, 0x5f, 0x60, 0x51,
+    0x7f, 0xa9, 0x19, 0xb5, 0x4a, 0x0d, 0x2d, 0xe5, 0x7a, 0x9f, 0x93, 0xc9, 0x9c, 0xef,
+    0xa0, 0xe0, 0x3b, 0x4d, 0xae, 0x2a, 0xf5, 0xb0, 0xc8, 0xeb, 0xbb, 0x3c, 0x83, 0x53,
+    0x99, 0x61, 0x17, 0x2b, 0x04, 0x7e, 0xba, 0x77, 0xd6, 0x26, 0xe1, 0x69, 0x14, 0x63,
+    0x55, 0x21, 0x0c, 0x7d,
+];
+
 fn reverse_sbox(byte: &mut u8) {
-    let sbox: [u8; 256] = [
-        0x52, 0x09, 0x6a, 0xd5, 0x30, 0x36, 0xa5, 0x38, 0xbf, 0x40, 0xa3, 0x9e, 0x81, 0xf3,
-        0xd7, 0xfb, 0x7c, 0xe3, 0x39, 0x82, 0x9b, 0x2f, 0xff, 0x87, 0x34, 0x8e, 0x43, 0x44,
-        0xc4, 0xde, 0xe9, 0xcb, 0x54, 0x7b, 0x94, 0x32, 0xa6, 0xc2, 0x23, 0x3d, 0xee, 0x4c,
-        0x95, 0x0b, 0x42, 0xfa, 0xc3, 0x4e, 0x08, 0x2e, 0xa1, 0x66, 0x28, 0xd9, 0x24, 0xb2,
-        0x76, 0x5b, 0xa2, 0x49, 0x6d, 0x8b, 0xd1, 0x25, 0x72, 0xf8, 0xf6, 0x64, 0x86, 0x68,
-        0x98, 0x16, 0xd4, 0xa4, 0x5c, 0xcc, 0x5d, 0x65, 0xb6, 0x92, 0x6c, 0x70, 0x48, 0x50,
-        0xfd, 0xed, 0xb9, 0xda, 0x5e, 0x15, 0x46, 0x57, 0xa7, 0x8d, 0x9d, 0x84, 0x90, 0xd8,
-        0xab, 0x00, 0x8c, 0xbc, 0xd3, 0x0a, 0xf7, 0xe4, 0x58, 0x05, 0xb8, 0xb3, 0x45, 0x06,
-        0xd0, 0x2c, 0x1e, 0x8f, 0xca, 0x3f, 0x0f, 0x02, 0xc1, 0xaf, 0xbd, 0x03, 0x01, 0x13,
-        0x8a, 0x6b, 0x3a, 0x91, 0x11, 0x41, 0x4f, 0x67, 0xdc, 0xea, 0x97, 0xf2, 0xcf, 0xce,
-        0xf0, 0xb4, 0xe6, 0x73, 0x96, 0xac, 0x74, 0x22, 0xe7, 0xad, 0x35, 0x85, 0xe2, 0xf9,
-        0x37, 0xe8, 0x1c, 0x75, 0xdf, 0x6e, 0x47, 0xf1, 0x1a, 0x71, 0x1d, 0x29, 0xc5, 0x89,
-        0x6f, 0xb7, 0x62, 0x0e, 0xaa, 0x18, 0xbe, 0x1b, 0xfc, 0x56, 0x3e, 0x4b, 0xc6, 0xd2,
-        0x79, 0x20, 0x9a, 0xdb, 0xc0, 0xfe, 0x78, 0xcd, 0x5a, 0xf4, 0x1f, 0xdd, 0xa8, 0x33,
-        0x88, 0x07, 0xc7, 0x31, 0xb1, 0x12, 0x10, 0x59, 0x27, 0x80, 0xec, 0x5f, 0x60, 0x51,
-        0x7f, 0xa9, 0x19, 0xb5, 0x4a, 0x0d, 0x2d, 0xe5, 0x7a, 0x9f, 0x93, 0xc9, 0x9c, 0xef,
-        0xa0, 0xe0, 0x3b, 0x4d, 0xae, 0x2a, 0xf5, 0xb0, 0xc8, 0xeb, 0xbb, 0x3c, 0x83, 0x53,
-        0x99, 0x61, 0x17, 0x2b, 0x04, 0x7e, 0xba, 0x77, 0xd6, 0x26, 0xe1, 0x69, 0x14, 0x63,
-        0x55, 0x21, 0x0c, 0x7d,
-    ];
-
-    *byte = sbox[*byte as usize];
+    *byte = INVERSE_SBOX[*byte as usize];
 }
 
 fn reverse_shift_rows(matrix: &mut [u8; 16]) {
@@ -306,7 +437,7 @@ fn reverse_mix_columns(matrix: &mut [u8; 16]) {
             matrix[(i * 4) + 3],
         ];
 
-        matrix[(i * 4)] = finite_field_multiplication(14, c[0])
+        matrix[i * 4] = finite_field_multiplication(14, c[0])
             ^ finite_field_multiplication(11, c[1])
             ^ finite_field_multiplication(13, c[2])
             ^ finite_field_multiplication(9, c[3]);
@@ -2,11 +2,14 @@ use crate::cryptography::aes::*;
 use crate::format::hex_to_u8;
 
 use rand::Rng;
+use std::slice;
 
 #[derive(Debug)]
 pub struct AES128 {
     pub init_key: [u8; 16],
     key: [u8; 176],
+    decryption_key: [u8; 176],
+    backend: AESBackend,
 }
 
 impl Default for AES128 {
@@ -17,19 +20,20 @@ impl Default for AES128 {
 
 impl AES128 {
     pub fn new() -> Self {
-        let init_key = AES128::generate_init_key();
-        let key = AES128::expand_key(init_key);
-        AES128 {
-            init_key,
-            key
-        }
+        AES128::from_init_key(AES128::generate_init_key())
     }
 
     pub fn from_init_key(init_key: [u8; 16]) -> Self {
+        AES128::with_backend(init_key, AESBackend::detect())
+    }
+
+    pub fn with_backend(init_key: [u8; 16], backend: AESBackend) -> Self {
         let key = AES128::expand_key(init_key);
         AES128 {
             init_key,
-            key
+            key,
+            decryption_key: ttable::decryption_key(&key),
+            backend: backend.or_supported()
         }
     }
     
@@ -43,12 +47,7 @@ impl AES128 {
             init_key[i] = *byte;
         }
 
-        let key = AES128::expand_key(init_key);
-
-        Ok(AES128 {
-                init_key,
-                key
-        })
+        Ok(AES128::from_init_key(init_key))
     }
 
     pub fn generate_init_key() -> [u8; 16] {
@@ -75,7 +74,7 @@ impl AES128 {
                 temp[j] = key[(j as u8 + c - 4) as usize];
             }
 
-            if c % 16 == 0 {
+            if c.is_multiple_of(16) {
                 schedule_core(&mut temp, i);
                 i += 1;
             }
@@ -96,7 +95,18 @@ impl AES128 {
         self.key != [0; 176]
     }
 
+    pub fn backend(&self) -> AESBackend {
+        self.backend
+    }
+
     pub fn encryption_algorithm(&self, matrix: &mut [u8; 16]) {
+        match self.backend {
+            AESBackend::Reference => {}
+            AESBackend::TTable => return ttable::encrypt_block(&self.key, matrix),
+            AESBackend::ConstantTime => return constant_time::encrypt_block(&self.key, matrix),
+            AESBackend::AESNI => return aesni::encrypt_blocks(&self.key, slice::from_mut(matrix)),
+        }
+
         add_key(matrix, &self.key[0..16]);
 
         for round in 1..=9 {
@@ -117,7 +127,28 @@ impl AES128 {
         add_key(matrix, &self.key[160..176]);
     }
 
+    pub fn encrypt_blocks(&self, blocks: &mut [[u8; 16]]) {
+        match self.backend {
+            AESBackend::AESNI => aesni::encrypt_blocks(&self.key, blocks),
+            _ => blocks.iter_mut().for_each(|block| self.encryption_algorithm(block)),
+        }
+    }
+
+    pub fn decrypt_blocks(&self, blocks: &mut [[u8; 16]]) {
+        match self.backend {
+            AESBackend::AESNI => aesni::decrypt_blocks(&self.decryption_key, blocks),
+            _ => blocks.iter_mut().for_each(|block| self.decryption_algorithm(block)),
+        }
+    }
+
     pub fn decryption_algorithm(&self, matrix: &mut [u8; 16]) {
+        match self.backend {
+            AESBackend::Reference => {}
+            AESBackend::TTable => return ttable::decrypt_block(&self.decryption_key, matrix),
+            AESBackend::ConstantTime => return constant_time::decrypt_block(&self.key, matrix),
+            AESBackend::AESNI => return aesni::decrypt_blocks(&self.decryption_key, slice::from_mut(matrix)),
+        }
+
         add_key(matrix, &self.key[160..176]);
         reverse_shift_rows(matrix);
 
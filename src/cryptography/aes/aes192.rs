@@ -3,11 +3,14 @@ use crate::cryptography::aes::*;
 use crate::format::hex_to_u8;
 
 use rand::Rng;
+use std::slice;
 
 #[derive(Debug)]
 pub struct AES192 {
     pub init_key: [u8; 24],
     key: [u8; 208],
+    decryption_key: [u8; 208],
+    backend: AESBackend,
 }
 
 impl Default for AES192 {
@@ -18,19 +21,20 @@ impl Default for AES192 {
 
 impl AES192 {
     pub fn new() -> Self {
-        let init_key = AES192::generate_init_key();
-        let key = AES192::expand_key(init_key);
-        AES192 {
-            init_key,
-            key
-        }
+        AES192::from_init_key(AES192::generate_init_key())
     }
     
     pub fn from_init_key(init_key: [u8; 24]) -> Self {
+        AES192::with_backend(init_key, AESBackend::detect())
+    }
+
+    pub fn with_backend(init_key: [u8; 24], backend: AESBackend) -> Self {
         let key = AES192::expand_key(init_key);
         AES192 {
             init_key,
-            key
+            key,
+            decryption_key: ttable::decryption_key(&key),
+            backend: backend.or_supported()
         }
     }
 
@@ -44,14 +48,7 @@ impl AES192 {
             init_key[i] = *byte;
         }
 
-        let key = AES192::expand_key(init_key);
-
-        Ok(
-            AES192 {
-                init_key,
-                key
-            }
-        )
+        Ok(AES192::from_init_key(init_key))
     }
 
     pub fn generate_init_key() -> [u8; 24] {
@@ -78,7 +75,7 @@ impl AES192 {
                 temp[j] = key[(j as u8 + c - 4) as usize];
             }
 
-            if c % 24 == 0 {
+            if c.is_multiple_of(24) {
                 schedule_core(&mut temp, i);
                 i += 1;
             }
@@ -99,7 +96,18 @@ impl AES192 {
         self.key != [0; 208]
     }
 
+    pub fn backend(&self) -> AESBackend {
+        self.backend
+    }
+
     pub fn encryption_algorithm(&self, matrix: &mut [u8; 16]) {
+        match self.backend {
+            AESBackend::Reference => {}
+            AESBackend::TTable => return ttable::encrypt_block(&self.key, matrix),
+            AESBackend::ConstantTime => return constant_time::encrypt_block(&self.key, matrix),
+            AESBackend::AESNI => return aesni::encrypt_blocks(&self.key, slice::from_mut(matrix)),
+        }
+
         add_key(matrix, &self.key[0..16]);
 
         for round in 1..=11 {
@@ -120,7 +128,28 @@ impl AES192 {
         add_key(matrix, &self.key[192..208]);
     }
 
+    pub fn encrypt_blocks(&self, blocks: &mut [[u8; 16]]) {
+        match self.backend {
+            AESBackend::AESNI => aesni::encrypt_blocks(&self.key, blocks),
+            _ => blocks.iter_mut().for_each(|block| self.encryption_algorithm(block)),
+        }
+    }
+
+    pub fn decrypt_blocks(&self, blocks: &mut [[u8; 16]]) {
+        match self.backend {
+            AESBackend::AESNI => aesni::decrypt_blocks(&self.decryption_key, blocks),
+            _ => blocks.iter_mut().for_each(|block| self.decryption_algorithm(block)),
+        }
+    }
+
     pub fn decryption_algorithm(&self, matrix: &mut [u8; 16]) {
+        match self.backend {
+            AESBackend::Reference => {}
+            AESBackend::TTable => return ttable::decrypt_block(&self.decryption_key, matrix),
+            AESBackend::ConstantTime => return constant_time::decrypt_block(&self.key, matrix),
+            AESBackend::AESNI => return aesni::decrypt_blocks(&self.decryption_key, slice::from_mut(matrix)),
+        }
+
         add_key(matrix, &self.key[192..208]);
         reverse_shift_rows(matrix);
 
@@ -3,11 +3,14 @@ use crate::cryptography::aes::*;
 use crate::format::hex_to_u8;
 
 use rand::Rng;
+use std::slice;
 
 #[derive(Debug)]
 pub struct AES256 {
     pub init_key: [u8; 32],
     key: [u8; 240],
+    decryption_key: [u8; 240],
+    backend: AESBackend,
 }
 
 impl Default for AES256 {
@@ -18,19 +21,20 @@ impl Default for AES256 {
 
 impl AES256 {
     pub fn new() -> Self {
-        let init_key = AES256::generate_init_key();
-        let key = AES256::expand_key(init_key);
-        AES256 {
-            init_key,
-            key
-        }
+        AES256::from_init_key(AES256::generate_init_key())
     }
     
     pub fn from_init_key(init_key: [u8; 32]) -> Self {
+        AES256::with_backend(init_key, AESBackend::detect())
+    }
+
+    pub fn with_backend(init_key: [u8; 32], backend: AESBackend) -> Self {
         let key = AES256::expand_key(init_key);
         AES256 {
             init_key,
-            key
+            key,
+            decryption_key: ttable::decryption_key(&key),
+            backend: backend.or_supported()
         }
     }
 
@@ -44,14 +48,7 @@ impl AES256 {
             init_key[i] = *byte;
         }
 
-        let key = AES256::expand_key(init_key);
-
-        Ok(
-            AES256 {
-                init_key,
-                key
-            }
-        )
+        Ok(AES256::from_init_key(init_key))
     }
 
     pub fn generate_init_key() -> [u8; 32] {
@@ -78,15 +75,13 @@ impl AES256 {
                 temp[j] = key[(j as u8 + c - 4) as usize];
             }
 
-            if c % 32 == 0 {
+            if c.is_multiple_of(32) {
                 schedule_core(&mut temp, i);
                 i += 1;
             }
 
-            if 32 == 16 {
-                for byte in temp.iter_mut() {
-                    sbox(byte);
-                }
+            if c % 32 == 16 {
+                temp = constant_time::sub_word(u32::from_be_bytes(temp)).to_be_bytes();
             }
 
             for byte in temp {
@@ -105,7 +100,18 @@ impl AES256 {
         self.key != [0; 240]
     }
 
+    pub fn backend(&self) -> AESBackend {
+        self.backend
+    }
+
     pub fn encryption_algorithm(&self, matrix: &mut [u8; 16]) {
+        match self.backend {
+            AESBackend::Reference => {}
+            AESBackend::TTable => return ttable::encrypt_block(&self.key, matrix),
+            AESBackend::ConstantTime => return constant_time::encrypt_block(&self.key, matrix),
+            AESBackend::AESNI => return aesni::encrypt_blocks(&self.key, slice::from_mut(matrix)),
+        }
+
         add_key(matrix, &self.key[0..16]);
 
         for round in 1..=13 {
@@ -126,7 +132,28 @@ impl AES256 {
         add_key(matrix, &self.key[224..240]);
     }
 
+    pub fn encrypt_blocks(&self, blocks: &mut [[u8; 16]]) {
+        match self.backend {
+            AESBackend::AESNI => aesni::encrypt_blocks(&self.key, blocks),
+            _ => blocks.iter_mut().for_each(|block| self.encryption_algorithm(block)),
+        }
+    }
+
+    pub fn decrypt_blocks(&self, blocks: &mut [[u8; 16]]) {
+        match self.backend {
+            AESBackend::AESNI => aesni::decrypt_blocks(&self.decryption_key, blocks),
+            _ => blocks.iter_mut().for_each(|block| self.decryption_algorithm(block)),
+        }
+    }
+
     pub fn decryption_algorithm(&self, matrix: &mut [u8; 16]) {
+        match self.backend {
+            AESBackend::Reference => {}
+            AESBackend::TTable => return ttable::decrypt_block(&self.decryption_key, matrix),
+            AESBackend::ConstantTime => return constant_time::decrypt_block(&self.key, matrix),
+            AESBackend::AESNI => return aesni::decrypt_blocks(&self.decryption_key, slice::from_mut(matrix)),
+        }
+
         add_key(matrix, &self.key[224..240]);
         reverse_shift_rows(matrix);
 
@@ -0,0 +1,104 @@
+// AES-NI instructions work on the FIPS 197 byte order, so the round keys from the
+// portable key schedule are loaded as they are. Decryption uses the equivalent
+// inverse cipher key schedule, the same one as the T-table backend.
+
+use crate::cryptography::aes::PARALLEL_BLOCKS;
+
+#[cfg(target_arch = "x86_64")]
+use std::arch::x86_64::*;
+
+#[cfg(target_arch = "x86_64")]
+pub(super) fn is_supported() -> bool {
+    is_x86_feature_detected!("aes") && is_x86_feature_detected!("sse2")
+}
+
+#[cfg(not(target_arch = "x86_64"))]
+pub(super) fn is_supported() -> bool {
+    false
+}
+
+pub(super) fn encrypt_blocks(key: &[u8], blocks: &mut [[u8; 16]]) {
+    assert!(is_supported(), "AES-NI is not supported on this CPU");
+
+    #[cfg(target_arch = "x86_64")]
+    // Safety: the CPU supports the instructions, checked above
+    unsafe {
+        encrypt_blocks_x86(key, blocks)
+    }
+}
+
+pub(super) fn decrypt_blocks(key: &[u8], blocks: &mut [[u8; 16]]) {
+    assert!(is_supported(), "AES-NI is not supported on this CPU");
+
+    #[cfg(target_arch = "x86_64")]
+    // Safety: the CPU supports the instructions, checked above
+    unsafe {
+        decrypt_blocks_x86(key, blocks)
+    }
+}
+
+#[cfg(target_arch = "x86_64")]
+#[target_feature(enable = "aes,sse2")]
+unsafe fn load_keys(key: &[u8]) -> [__m128i; 15] {
+    let mut keys = [_mm_setzero_si128(); 15];
+    for (round_key, bytes) in keys.iter_mut().zip(key.chunks(16)) {
+        *round_key = _mm_loadu_si128(bytes.as_ptr() as *const __m128i);
+    }
+    keys
+}
+
+// Several independent blocks go through each round together so the latency of one
+// aesenc is hidden behind the others
+#[cfg(target_arch = "x86_64")]
+#[target_feature(enable = "aes,sse2")]
+unsafe fn encrypt_blocks_x86(key: &[u8], blocks: &mut [[u8; 16]]) {
+    let rounds = key.len() / 16 - 1;
+    let keys = load_keys(key);
+
+    for chunk in blocks.chunks_mut(PARALLEL_BLOCKS) {
+        let mut state = [_mm_setzero_si128(); PARALLEL_BLOCKS];
+        let state = &mut state[..chunk.len()];
+
+        for (s, block) in state.iter_mut().zip(chunk.iter()) {
+            *s = _mm_xor_si128(_mm_loadu_si128(block.as_ptr() as *const __m128i), keys[0]);
+        }
+
+        for round_key in &keys[1..rounds] {
+            for s in state.iter_mut() {
+                *s = _mm_aesenc_si128(*s, *round_key);
+            }
+        }
+
+        for (s, block) in state.iter().zip(chunk.iter_mut()) {
+            let s = _mm_aesenclast_si128(*s, keys[rounds]);
+            _mm_storeu_si128(block.as_mut_ptr() as *mut __m128i, s);
+        }
+    }
+}
+
+#[cfg(target_arch = "x86_64")]
+#[target_feature(enable = "aes,sse2")]
+unsafe fn decrypt_blocks_x86(key: &[u8], blocks: &mut [[u8; 16]]) {
+    let rounds = key.len() / 16 - 1;
+    let keys = load_keys(key);
+
+    for chunk in blocks.chunks_mut(PARALLEL_BLOCKS) {
+        let mut state = [_mm_setzero_si128(); PARALLEL_BLOCKS];
+        let state = &mut state[..chunk.len()];
+
+        for (s, block) in state.iter_mut().zip(chunk.iter()) {
+            *s = _mm_xor_si128(_mm_loadu_si128(block.as_ptr() as *const __m128i), keys[0]);
+        }
+
+        for round_key in &keys[1..rounds] {
+            for s in state.iter_mut() {
+                *s = _mm_aesdec_si128(*s, *round_key);
+            }
+        }
+
+        for (s, block) in state.iter().zip(chunk.iter_mut()) {
+            let s = _mm_aesdeclast_si128(*s, keys[rounds]);
+            _mm_storeu_si128(block.as_mut_ptr() as *mut __m128i, s);
+        }
+    }
+}
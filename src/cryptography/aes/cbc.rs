@@ -0,0 +1,84 @@
+use crate::cryptography::aes::{AESError, AES};
+
+pub struct CBC {
+    aes: AES,
+    iv: [u8; 16],
+}
+
+impl CBC {
+    pub fn new(aes: AES, iv: [u8; 16]) -> Self {
+        CBC { aes, iv }
+    }
+
+    pub fn iv(&self) -> [u8; 16] {
+        self.iv
+    }
+
+    pub fn encrypt(&self, message: &[u8]) -> Vec<u8> {
+        // PKCS#7: always pad, with a full block when the message is already aligned
+        let padding = 16 - message.len() % 16;
+        let mut message = message.to_vec();
+        message.resize(message.len() + padding, padding as u8);
+
+        let mut cipher = Vec::with_capacity(message.len());
+        let mut previous = self.iv;
+
+        for slice in message.chunks(16) {
+            let mut matrix = [0; 16];
+            for i in 0..16 {
+                matrix[i] = slice[i] ^ previous[i];
+            }
+
+            self.aes.encrypt_block(&mut matrix);
+
+            cipher.extend_from_slice(&matrix);
+            previous = matrix;
+        }
+
+        cipher
+    }
+
+    pub fn decrypt(&self, cipher: &[u8]) -> Result<Vec<u8>, AESError> {
+        if cipher.is_empty() || !cipher.len().is_multiple_of(16) {
+            return Err(AESError::InvalidLength);
+        }
+
+        let mut message = Vec::with_capacity(cipher.len());
+        let mut previous = self.iv;
+
+        for slice in cipher.chunks(16) {
+            let mut matrix = [0; 16];
+            matrix.copy_from_slice(slice);
+
+            self.aes.decrypt_block(&mut matrix);
+
+            for i in 0..16 {
+                matrix[i] ^= previous[i];
+            }
+
+            message.extend_from_slice(&matrix);
+            previous.copy_from_slice(slice);
+        }
+
+        let padding = unpad_length(&message[message.len() - 16..])?;
+        message.truncate(message.len() - padding);
+
+        Ok(message)
+    }
+}
+
+// Checks every byte of the last block so the time taken does not depend on where the padding breaks
+fn unpad_length(block: &[u8]) -> Result<usize, AESError> {
+    let padding = block[15];
+    let mut invalid = (padding == 0) as u8 | (padding > 16) as u8;
+
+    for (i, byte) in block.iter().enumerate() {
+        let in_padding = (15 - i < padding as usize) as u8;
+        invalid |= in_padding & (*byte != padding) as u8;
+    }
+
+    match invalid {
+        0 => Ok(padding as usize),
+        _ => Err(AESError::InvalidPadding),
+    }
+}
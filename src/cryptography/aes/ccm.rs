@@ -0,0 +1,135 @@
+use crate::cryptography::aes::ctr::apply_keystream;
+use crate::cryptography::aes::{AESError, AES};
+use crate::cryptography::constant_time_eq;
+
+pub struct CCM {
+    aes: AES,
+    tag_size: usize,
+    nonce_size: usize,
+}
+
+impl CCM {
+    pub fn new(aes: AES, tag_size: usize, nonce_size: usize) -> Result<Self, AESError> {
+        if !(4..=16).contains(&tag_size) || !tag_size.is_multiple_of(2) {
+            return Err(AESError::InccorectSize);
+        }
+
+        if !(7..=13).contains(&nonce_size) {
+            return Err(AESError::InccorectSize);
+        }
+
+        Ok(CCM {
+            aes,
+            tag_size,
+            nonce_size,
+        })
+    }
+
+    // Returns the ciphertext followed by the tag
+    pub fn encrypt(&self, nonce: &[u8], aad: &[u8], message: &[u8]) -> Result<Vec<u8>, AESError> {
+        self.check_lengths(nonce, message.len())?;
+
+        let tag = self.cbc_mac(nonce, aad, message);
+        let a0 = self.counter_block(nonce);
+
+        let mut cipher = apply_keystream(&self.aes, &a0, self.length_size(), 16, message)?;
+        cipher.append(&mut self.encrypt_tag(&a0, tag));
+
+        Ok(cipher)
+    }
+
+    pub fn decrypt(&self, nonce: &[u8], aad: &[u8], cipher: &[u8]) -> Result<Vec<u8>, AESError> {
+        if cipher.len() < self.tag_size {
+            return Err(AESError::InvalidLength);
+        }
+
+        let (cipher, tag) = cipher.split_at(cipher.len() - self.tag_size);
+        self.check_lengths(nonce, cipher.len())?;
+
+        let a0 = self.counter_block(nonce);
+        let message = apply_keystream(&self.aes, &a0, self.length_size(), 16, cipher)?;
+
+        let expected = self.encrypt_tag(&a0, self.cbc_mac(nonce, aad, &message));
+        if !constant_time_eq(&expected, tag) {
+            return Err(AESError::AuthenticationFailed);
+        }
+
+        Ok(message)
+    }
+
+    fn length_size(&self) -> usize {
+        15 - self.nonce_size
+    }
+
+    fn check_lengths(&self, nonce: &[u8], length: usize) -> Result<(), AESError> {
+        if nonce.len() != self.nonce_size {
+            return Err(AESError::InccorectSize);
+        }
+
+        if self.length_size() < 8 && (length as u64) >> (8 * self.length_size()) != 0 {
+            return Err(AESError::InvalidLength);
+        }
+
+        Ok(())
+    }
+
+    fn counter_block(&self, nonce: &[u8]) -> [u8; 16] {
+        let mut a0 = [0; 16];
+        a0[0] = (self.length_size() - 1) as u8;
+        a0[1..=self.nonce_size].copy_from_slice(nonce);
+        a0
+    }
+
+    fn encrypt_tag(&self, a0: &[u8; 16], tag: [u8; 16]) -> Vec<u8> {
+        let mut s0 = *a0;
+        self.aes.encrypt_block(&mut s0);
+
+        tag.iter()
+            .zip(s0)
+            .take(self.tag_size)
+            .map(|(t, s)| t ^ s)
+            .collect()
+    }
+
+    fn cbc_mac(&self, nonce: &[u8], aad: &[u8], message: &[u8]) -> [u8; 16] {
+        // B_0 = flags || nonce || l(m)
+        let mut b0 = [0; 16];
+        b0[0] = ((!aad.is_empty() as u8) << 6)
+            | ((((self.tag_size - 2) / 2) as u8) << 3)
+            | (self.length_size() - 1) as u8;
+        b0[1..=self.nonce_size].copy_from_slice(nonce);
+        let length = (message.len() as u128).to_be_bytes();
+        b0[16 - self.length_size()..].copy_from_slice(&length[16 - self.length_size()..]);
+
+        let mut data = b0.to_vec();
+
+        if !aad.is_empty() {
+            let length = aad.len() as u64;
+            if length < 0xff00 {
+                data.extend_from_slice(&(length as u16).to_be_bytes());
+            } else if length <= u32::MAX as u64 {
+                data.extend_from_slice(&[0xff, 0xfe]);
+                data.extend_from_slice(&(length as u32).to_be_bytes());
+            } else {
+                data.extend_from_slice(&[0xff, 0xff]);
+                data.extend_from_slice(&length.to_be_bytes());
+            }
+
+            data.extend_from_slice(aad);
+            data.resize(data.len().next_multiple_of(16), 0);
+        }
+
+        data.extend_from_slice(message);
+        data.resize(data.len().next_multiple_of(16), 0);
+
+        let mut tag = [0; 16];
+        for block in data.chunks(16) {
+            for (t, b) in tag.iter_mut().zip(block) {
+                *t ^= b;
+            }
+            self.aes.encrypt_block(&mut tag);
+        }
+
+        tag
+    }
+}
@@ -0,0 +1,70 @@
+use crate::cryptography::aes::AES;
+use crate::cryptography::CryptographicAlgorithm;
+
+#[derive(Clone, Copy)]
+pub enum CFBType {
+    CFB8,
+    CFB128,
+}
+
+pub struct CFB {
+    aes: AES,
+    iv: [u8; 16],
+    kind: CFBType,
+}
+
+impl CFB {
+    pub fn new(aes: AES, iv: [u8; 16], kind: CFBType) -> Self {
+        CFB { aes, iv, kind }
+    }
+
+    pub fn iv(&self) -> [u8; 16] {
+        self.iv
+    }
+
+    // The shift register is fed with the ciphertext in both directions
+    fn process(&self, input: &[u8], decrypt: bool) -> Vec<u8> {
+        let mut output = Vec::with_capacity(input.len());
+        let mut register = self.iv;
+
+        match self.kind {
+            CFBType::CFB8 => {
+                for byte in input {
+                    let mut keystream = register;
+                    self.aes.encrypt_block(&mut keystream);
+
+                    let result = byte ^ keystream[0];
+                    let cipher = if decrypt { *byte } else { result };
+
+                    register.copy_within(1.., 0);
+                    register[15] = cipher;
+                    output.push(result);
+                }
+            }
+            CFBType::CFB128 => {
+                for slice in input.chunks(16) {
+                    let mut keystream = register;
+                    self.aes.encrypt_block(&mut keystream);
+
+                    for (i, byte) in slice.iter().enumerate() {
+                        let result = byte ^ keystream[i];
+                        register[i] = if decrypt { *byte } else { result };
+                        output.push(result);
+                    }
+                }
+            }
+        }
+
+        output
+    }
+}
+
+impl CryptographicAlgorithm for CFB {
+    fn encrypt(&self, message: &[u8]) -> Vec<u8> {
+        self.process(message, false)
+    }
+
+    fn decrypt(&self, cipher: &[u8]) -> Vec<u8> {
+        self.process(cipher, true)
+    }
+}
@@ -0,0 +1,67 @@
+use crate::cryptography::aes::AES;
+use crate::cryptography::constant_time_eq;
+
+pub struct CMAC {
+    aes: AES,
+    k1: [u8; 16],
+    k2: [u8; 16],
+}
+
+impl CMAC {
+    pub fn new(aes: AES) -> Self {
+        let mut l = [0; 16];
+        aes.encrypt_block(&mut l);
+
+        let k1 = double(&l);
+        let k2 = double(&k1);
+
+        CMAC { aes, k1, k2 }
+    }
+
+    pub fn subkeys(&self) -> ([u8; 16], [u8; 16]) {
+        (self.k1, self.k2)
+    }
+
+    pub fn mac(&self, message: &[u8]) -> Vec<u8> {
+        // The last block is masked with K1 when complete and with K2 after 10* padding
+        let blocks = message.len().div_ceil(16).max(1);
+        let (head, last) = message.split_at((blocks - 1) * 16);
+
+        let mut final_block = [0; 16];
+        final_block[..last.len()].copy_from_slice(last);
+        let subkey = match last.len() {
+            16 => &self.k1,
+            length => {
+                final_block[length] = 0x80;
+                &self.k2
+            }
+        };
+
+        let mut tag = [0; 16];
+        for block in head.chunks(16) {
+            for (t, b) in tag.iter_mut().zip(block) {
+                *t ^= b;
+            }
+            self.aes.encrypt_block(&mut tag);
+        }
+
+        for ((t, b), k) in tag.iter_mut().zip(final_block).zip(subkey) {
+            *t ^= b ^ k;
+        }
+        self.aes.encrypt_block(&mut tag);
+
+        tag.to_vec()
+    }
+
+    pub fn verify(&self, message: &[u8], tag: &[u8]) -> bool {
+        constant_time_eq(&self.mac(message), tag)
+    }
+}
+
+// Multiplication by x in GF(2^128) with the big-endian convention of SP 800-38B
+fn double(block: &[u8; 16]) -> [u8; 16] {
+    let value = u128::from_be_bytes(*block);
+    let carry = value >> 127;
+
+    ((value << 1) ^ (0x87 & carry.wrapping_neg())).to_be_bytes()
+}
@@ -0,0 +1,170 @@
+// The state is kept as four 32-bit columns, SubBytes packs them into one 128-bit
+// word and works on all sixteen bytes at once with shifts and masks. The S-box is
+// computed as the inverse in GF(2^8) followed by the affine transform, so no memory
+// access or branch depends on the key or the data.
+
+const LOW_BITS: u128 = 0x01010101010101010101010101010101;
+
+fn xtime(word: u128) -> u128 {
+    ((word & (LOW_BITS * 0x7f)) << 1) ^ (((word >> 7) & LOW_BITS) * 0x1b)
+}
+
+fn multiply(mut a: u128, b: u128) -> u128 {
+    let mut product = 0;
+
+    for i in 0..8 {
+        product ^= a & (((b >> i) & LOW_BITS) * 0xff);
+        a = xtime(a);
+    }
+
+    product
+}
+
+fn square(word: u128, times: usize) -> u128 {
+    let mut word = word;
+    for _ in 0..times {
+        word = multiply(word, word);
+    }
+    word
+}
+
+// x^254 is the multiplicative inverse, and maps 0 to 0 as the S-box requires
+fn inverse(x: u128) -> u128 {
+    let x2 = square(x, 1);
+    let x3 = multiply(x2, x);
+    let x12 = square(x3, 2);
+    let x14 = multiply(x12, x2);
+    let x15 = multiply(x12, x3);
+    let x240 = square(x15, 4);
+    multiply(x240, x14)
+}
+
+fn rotate_bytes(word: u128, amount: u32) -> u128 {
+    let left = (word << amount) & (LOW_BITS * ((0xff << amount) & 0xff));
+    let right = (word >> (8 - amount)) & (LOW_BITS * (0xff >> (8 - amount)));
+    left | right
+}
+
+fn sub_bytes(word: u128) -> u128 {
+    let b = inverse(word);
+    b ^ rotate_bytes(b, 1)
+        ^ rotate_bytes(b, 2)
+        ^ rotate_bytes(b, 3)
+        ^ rotate_bytes(b, 4)
+        ^ (LOW_BITS * 0x63)
+}
+
+fn reverse_sub_bytes(word: u128) -> u128 {
+    inverse(
+        rotate_bytes(word, 1) ^ rotate_bytes(word, 3) ^ rotate_bytes(word, 6) ^ (LOW_BITS * 0x05),
+    )
+}
+
+pub(super) fn sub_word(word: u32) -> u32 {
+    sub_bytes(word as u128) as u32
+}
+
+fn substitute(state: [u32; 4], sbox: fn(u128) -> u128) -> [u32; 4] {
+    let packed = state
+        .iter()
+        .fold(0, |packed, word| (packed << 32) | *word as u128);
+    let substituted = sbox(packed);
+
+    let mut output = [0; 4];
+    for (i, word) in output.iter_mut().enumerate() {
+        *word = (substituted >> (96 - 32 * i)) as u32;
+    }
+    output
+}
+
+// Byte r of the output column comes from column (c + shift[r]) of the input
+fn shift_rows(state: [u32; 4], shift: [usize; 4]) -> [u32; 4] {
+    let mut output = [0; 4];
+
+    for (c, word) in output.iter_mut().enumerate() {
+        *word = (state[(c + shift[0]) % 4] & 0xff000000)
+            | (state[(c + shift[1]) % 4] & 0x00ff0000)
+            | (state[(c + shift[2]) % 4] & 0x0000ff00)
+            | (state[(c + shift[3]) % 4] & 0x000000ff);
+    }
+
+    output
+}
+
+fn mix_column(word: u32) -> u32 {
+    let rotated = word.rotate_left(8);
+    xtime((word ^ rotated) as u128) as u32 ^ rotated ^ word.rotate_left(16) ^ word.rotate_left(24)
+}
+
+// InvMixColumns factors into a cheap preprocessing step followed by MixColumns
+pub(super) fn reverse_mix_column(word: u32) -> u32 {
+    mix_column(word ^ xtime(xtime((word ^ word.rotate_left(16)) as u128)) as u32)
+}
+
+fn load(matrix: &[u8; 16]) -> [u32; 4] {
+    let mut state = [0; 4];
+    for (word, bytes) in state.iter_mut().zip(matrix.chunks(4)) {
+        *word = u32::from_be_bytes(bytes.try_into().unwrap());
+    }
+    state
+}
+
+fn store(state: [u32; 4], matrix: &mut [u8; 16]) {
+    for (bytes, word) in matrix.chunks_mut(4).zip(state) {
+        bytes.copy_from_slice(&word.to_be_bytes());
+    }
+}
+
+fn add_round_key(state: &mut [u32; 4], key: &[u8], round: usize) {
+    for (c, word) in state.iter_mut().enumerate() {
+        let offset = round * 16 + c * 4;
+        *word ^= u32::from_be_bytes(key[offset..offset + 4].try_into().unwrap());
+    }
+}
+
+const ENCRYPTION_SHIFT: [usize; 4] = [0, 1, 2, 3];
+const DECRYPTION_SHIFT: [usize; 4] = [0, 3, 2, 1];
+
+pub(super) fn encrypt_block(key: &[u8], matrix: &mut [u8; 16]) {
+    let rounds = key.len() / 16 - 1;
+
+    let mut state = load(matrix);
+    add_round_key(&mut state, key, 0);
+
+    for round in 1..=rounds {
+        state = substitute(state, sub_bytes);
+        state = shift_rows(state, ENCRYPTION_SHIFT);
+
+        if round != rounds {
+            for word in state.iter_mut() {
+                *word = mix_column(*word);
+            }
+        }
+
+        add_round_key(&mut state, key, round);
+    }
+
+    store(state, matrix);
+}
+
+pub(super) fn decrypt_block(key: &[u8], matrix: &mut [u8; 16]) {
+    let rounds = key.len() / 16 - 1;
+
+    let mut state = load(matrix);
+    add_round_key(&mut state, key, rounds);
+
+    for round in (0..rounds).rev() {
+        state = shift_rows(state, DECRYPTION_SHIFT);
+        state = substitute(state, reverse_sub_bytes);
+
+        add_round_key(&mut state, key, round);
+
+        if round != 0 {
+            for word in state.iter_mut() {
+                *word = reverse_mix_column(*word);
+            }
+        }
+    }
+
+    store(state, matrix);
+}
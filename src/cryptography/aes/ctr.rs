@@ -0,0 +1,115 @@
+use crate::cryptography::aes::{AESError, AES, PARALLEL_BLOCKS};
+
+// The counter occupies the last `counter_size` bytes of the block and wraps within them,
+// the bytes in front of it are a fixed nonce. Using more blocks than the counter can
+// count would repeat the keystream, so that fails with InvalidLength instead.
+pub struct CTR {
+    aes: AES,
+    iv: [u8; 16],
+    counter_size: usize,
+}
+
+impl CTR {
+    pub fn new(aes: AES, iv: [u8; 16]) -> Self {
+        CTR {
+            aes,
+            iv,
+            counter_size: 16,
+        }
+    }
+
+    pub fn from_nonce(aes: AES, nonce: &[u8], counter: u128) -> Result<Self, AESError> {
+        if nonce.is_empty() || nonce.len() > 15 {
+            return Err(AESError::InccorectSize);
+        }
+
+        let counter_size = 16 - nonce.len();
+        if counter_size < 16 && counter >> (8 * counter_size) != 0 {
+            return Err(AESError::InccorectSize);
+        }
+
+        let mut iv = counter.to_be_bytes();
+        iv[..nonce.len()].copy_from_slice(nonce);
+
+        Ok(CTR {
+            aes,
+            iv,
+            counter_size,
+        })
+    }
+
+    pub fn iv(&self) -> [u8; 16] {
+        self.iv
+    }
+
+    pub fn counter_block(&self, index: u128) -> [u8; 16] {
+        counter_block(&self.iv, self.counter_size, index)
+    }
+
+    // Encrypts or decrypts `data` as if it started `offset` bytes into the stream
+    pub fn apply_keystream(&self, offset: u64, data: &[u8]) -> Result<Vec<u8>, AESError> {
+        apply_keystream(&self.aes, &self.iv, self.counter_size, offset, data)
+    }
+
+    pub fn encrypt(&self, message: &[u8]) -> Result<Vec<u8>, AESError> {
+        self.apply_keystream(0, message)
+    }
+
+    pub fn decrypt(&self, cipher: &[u8]) -> Result<Vec<u8>, AESError> {
+        self.apply_keystream(0, cipher)
+    }
+}
+
+pub(super) fn counter_block(iv: &[u8; 16], counter_size: usize, index: u128) -> [u8; 16] {
+    let mask = match counter_size {
+        16 => u128::MAX,
+        size => (1 << (8 * size)) - 1,
+    };
+
+    let iv = u128::from_be_bytes(*iv);
+    let counter = (iv & mask).wrapping_add(index) & mask;
+
+    ((iv & !mask) | counter).to_be_bytes()
+}
+
+pub(super) fn apply_keystream(
+    aes: &AES,
+    iv: &[u8; 16],
+    counter_size: usize,
+    offset: u64,
+    data: &[u8],
+) -> Result<Vec<u8>, AESError> {
+    let mut index = (offset / 16) as u128;
+    let mut skip = (offset % 16) as usize;
+
+    if counter_size < 16
+        && index + (skip + data.len()).div_ceil(16) as u128 > 1 << (8 * counter_size)
+    {
+        return Err(AESError::InvalidLength);
+    }
+
+    let mut output = Vec::with_capacity(data.len());
+
+    // Keystream blocks are generated a batch at a time so the backend can pipeline them
+    let mut remaining = data;
+    while !remaining.is_empty() {
+        let count = (skip + remaining.len()).div_ceil(16).min(PARALLEL_BLOCKS);
+        let mut keystream = [[0; 16]; PARALLEL_BLOCKS];
+        for (i, block) in keystream[..count].iter_mut().enumerate() {
+            *block = counter_block(iv, counter_size, index + i as u128);
+        }
+        aes.encrypt_blocks(&mut keystream[..count]);
+
+        let length = remaining.len().min(16 * count - skip);
+        let keystream = keystream[..count].iter().flatten().skip(skip);
+        for (byte, key) in remaining[..length].iter().zip(keystream) {
+            output.push(byte ^ key);
+        }
+
+        remaining = &remaining[length..];
+        index += count as u128;
+        skip = 0;
+    }
+
+    Ok(output)
+}
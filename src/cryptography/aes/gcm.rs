@@ -0,0 +1,102 @@
+use crate::cryptography::aes::ctr::apply_keystream;
+use crate::cryptography::aes::{AESError, AES};
+use crate::cryptography::constant_time_eq;
+use crate::math::ghash;
+
+// SP 800-38D allows 2^32 - 2 blocks, the 32-bit counter would wrap back to J0 after that
+const MAX_MESSAGE_LENGTH: u64 = (1 << 36) - 32;
+
+pub struct GCM {
+    aes: AES,
+    h: u128,
+}
+
+impl GCM {
+    pub fn new(aes: AES) -> Self {
+        let mut h = [0; 16];
+        aes.encrypt_block(&mut h);
+
+        GCM {
+            aes,
+            h: u128::from_be_bytes(h),
+        }
+    }
+
+    // Returns the ciphertext followed by the 128-bit tag
+    pub fn encrypt(
+        &self,
+        nonce: &[u8; 12],
+        aad: &[u8],
+        message: &[u8],
+    ) -> Result<Vec<u8>, AESError> {
+        if message.len() as u64 > MAX_MESSAGE_LENGTH {
+            return Err(AESError::InvalidLength);
+        }
+
+        let j0 = pre_counter_block(nonce);
+        let mut cipher = apply_keystream(&self.aes, &j0, 4, 16, message)?;
+
+        let tag = self.tag(&j0, aad, &cipher);
+        cipher.extend_from_slice(&tag);
+
+        Ok(cipher)
+    }
+
+    pub fn decrypt(
+        &self,
+        nonce: &[u8; 12],
+        aad: &[u8],
+        cipher: &[u8],
+    ) -> Result<Vec<u8>, AESError> {
+        if cipher.len() < 16 || (cipher.len() - 16) as u64 > MAX_MESSAGE_LENGTH {
+            return Err(AESError::InvalidLength);
+        }
+
+        let (cipher, tag) = cipher.split_at(cipher.len() - 16);
+        let j0 = pre_counter_block(nonce);
+
+        if !constant_time_eq(&self.tag(&j0, aad, cipher), tag) {
+            return Err(AESError::AuthenticationFailed);
+        }
+
+        apply_keystream(&self.aes, &j0, 4, 16, cipher)
+    }
+
+    fn tag(&self, j0: &[u8; 16], aad: &[u8], cipher: &[u8]) -> [u8; 16] {
+        let mut tag = *j0;
+        self.aes.encrypt_block(&mut tag);
+
+        let s = ghash(self.h, aad, cipher).to_be_bytes();
+        for (byte, s) in tag.iter_mut().zip(s) {
+            *byte ^= s;
+        }
+
+        tag
+    }
+}
+
+// GCM without a message, the tag authenticates the associated data only
+pub struct GMAC {
+    gcm: GCM,
+}
+
+impl GMAC {
+    pub fn new(aes: AES) -> Self {
+        GMAC { gcm: GCM::new(aes) }
+    }
+
+    pub fn mac(&self, nonce: &[u8; 12], aad: &[u8]) -> Vec<u8> {
+        self.gcm.tag(&pre_counter_block(nonce), aad, &[]).to_vec()
+    }
+
+    pub fn verify(&self, nonce: &[u8; 12], aad: &[u8], tag: &[u8]) -> bool {
+        constant_time_eq(&self.mac(nonce, aad), tag)
+    }
+}
+
+fn pre_counter_block(nonce: &[u8; 12]) -> [u8; 16] {
+    let mut j0 = [0; 16];
+    j0[..12].copy_from_slice(nonce);
+    j0[15] = 1;
+    j0
+}
@@ -0,0 +1,133 @@
+use crate::cryptography::aes::{AESError, InitKey, AES};
+use crate::cryptography::constant_time_eq;
+use crate::math::polyval;
+
+// Nonce-misuse-resistant AEAD from RFC 8452, repeating a nonce only reveals repeated messages
+pub struct GCMSIV {
+    aes: AES,
+}
+
+impl GCMSIV {
+    pub fn new(aes: AES) -> Result<Self, AESError> {
+        match aes {
+            AES::AES192(_) => Err(AESError::InccorectSize),
+            _ => Ok(GCMSIV { aes }),
+        }
+    }
+
+    // Returns the ciphertext followed by the 128-bit tag
+    pub fn encrypt(&self, nonce: &[u8; 12], aad: &[u8], message: &[u8]) -> Vec<u8> {
+        let (authentication_key, encryption_key) = self.derive_keys(nonce);
+
+        let tag = tag(&authentication_key, &encryption_key, nonce, aad, message);
+        let mut cipher = apply_keystream(&encryption_key, &tag, message);
+        cipher.extend_from_slice(&tag);
+
+        cipher
+    }
+
+    pub fn decrypt(
+        &self,
+        nonce: &[u8; 12],
+        aad: &[u8],
+        cipher: &[u8],
+    ) -> Result<Vec<u8>, AESError> {
+        if cipher.len() < 16 {
+            return Err(AESError::InvalidLength);
+        }
+
+        let (cipher, received) = cipher.split_at(cipher.len() - 16);
+        let (authentication_key, encryption_key) = self.derive_keys(nonce);
+
+        let mut tag = [0; 16];
+        tag.copy_from_slice(received);
+        let message = apply_keystream(&encryption_key, &tag, cipher);
+
+        let expected = self::tag(&authentication_key, &encryption_key, nonce, aad, &message);
+        if !constant_time_eq(&expected, received) {
+            return Err(AESError::AuthenticationFailed);
+        }
+
+        Ok(message)
+    }
+
+    // Each block yields eight bytes of key material: two blocks for POLYVAL, the rest for AES
+    fn derive_keys(&self, nonce: &[u8; 12]) -> ([u8; 16], AES) {
+        let blocks: u32 = match self.aes {
+            AES::AES256(_) => 6,
+            _ => 4,
+        };
+
+        let mut material = Vec::with_capacity(8 * blocks as usize);
+        for i in 0..blocks {
+            let mut block = [0; 16];
+            block[..4].copy_from_slice(&i.to_le_bytes());
+            block[4..].copy_from_slice(nonce);
+            self.aes.encrypt_block(&mut block);
+
+            material.extend_from_slice(&block[..8]);
+        }
+
+        let mut authentication_key = [0; 16];
+        authentication_key.copy_from_slice(&material[..16]);
+        // The derived key keeps the backend the caller chose for the key generating key
+        let encryption_key = AES::with_backend(
+            InitKey::from_slice(&material[16..]).unwrap(),
+            self.aes.backend(),
+        );
+
+        (authentication_key, encryption_key)
+    }
+}
+
+fn tag(
+    authentication_key: &[u8; 16],
+    encryption_key: &AES,
+    nonce: &[u8; 12],
+    aad: &[u8],
+    message: &[u8],
+) -> [u8; 16] {
+    let mut data = aad.to_vec();
+    data.resize(data.len().next_multiple_of(16), 0);
+    data.extend_from_slice(message);
+    data.resize(data.len().next_multiple_of(16), 0);
+    data.extend_from_slice(&(aad.len() as u64 * 8).to_le_bytes());
+    data.extend_from_slice(&(message.len() as u64 * 8).to_le_bytes());
+
+    let mut s = polyval(authentication_key, &data);
+    for (byte, n) in s.iter_mut().zip(nonce) {
+        *byte ^= n;
+    }
+    s[15] &= 0x7f;
+
+    encryption_key.encrypt_block(&mut s);
+    s
+}
+
+// CTR mode with the tag as initial counter block and a 32-bit little-endian counter
+fn apply_keystream(encryption_key: &AES, tag: &[u8; 16], data: &[u8]) -> Vec<u8> {
+    let mut counter_block = *tag;
+    counter_block[15] |= 0x80;
+
+    let mut counter = u32::from_le_bytes([
+        counter_block[0],
+        counter_block[1],
+        counter_block[2],
+        counter_block[3],
+    ]);
+
+    let mut output = Vec::with_capacity(data.len());
+    for slice in data.chunks(16) {
+        let mut keystream = counter_block;
+        keystream[..4].copy_from_slice(&counter.to_le_bytes());
+        encryption_key.encrypt_block(&mut keystream);
+
+        for (byte, key) in slice.iter().zip(keystream) {
+            output.push(byte ^ key);
+        }
+
+        counter = counter.wrapping_add(1);
+    }
+
+    output
+}
@@ -0,0 +1,158 @@
+use crate::cryptography::aes::{AESError, InitKey, AES};
+use crate::cryptography::constant_time_eq;
+
+const DEFAULT_IV: [u8; 8] = [0xa6; 8];
+const PADDING_IV: [u8; 4] = [0xa6, 0x59, 0x59, 0xa6];
+
+// AES key wrap (RFC 3394) and key wrap with padding (RFC 5649) under a key-encryption key
+pub struct KeyWrap {
+    kek: AES,
+}
+
+impl KeyWrap {
+    pub fn new(kek: AES) -> Self {
+        KeyWrap { kek }
+    }
+
+    pub fn wrap(&self, key: &InitKey) -> Vec<u8> {
+        self.wrap_blocks(DEFAULT_IV, key.as_bytes())
+    }
+
+    pub fn unwrap(&self, wrapped: &[u8]) -> Result<InitKey, AESError> {
+        if wrapped.len() < 24 || !wrapped.len().is_multiple_of(8) {
+            return Err(AESError::InvalidLength);
+        }
+
+        let (iv, key) = self.unwrap_blocks(wrapped);
+        if !constant_time_eq(&iv, &DEFAULT_IV) {
+            return Err(AESError::AuthenticationFailed);
+        }
+
+        InitKey::from_slice(&key)
+    }
+
+    pub fn wrap_with_padding(&self, key: &[u8]) -> Result<Vec<u8>, AESError> {
+        if key.is_empty() || key.len() > u32::MAX as usize {
+            return Err(AESError::InvalidLength);
+        }
+
+        let mut iv = [0; 8];
+        iv[..4].copy_from_slice(&PADDING_IV);
+        iv[4..].copy_from_slice(&(key.len() as u32).to_be_bytes());
+
+        let mut padded = key.to_vec();
+        padded.resize(key.len().next_multiple_of(8), 0);
+
+        // A single padded block is encrypted directly together with the IV
+        if padded.len() == 8 {
+            let mut block = [0; 16];
+            block[..8].copy_from_slice(&iv);
+            block[8..].copy_from_slice(&padded);
+            self.kek.encrypt_block(&mut block);
+
+            return Ok(block.to_vec());
+        }
+
+        Ok(self.wrap_blocks(iv, &padded))
+    }
+
+    pub fn unwrap_with_padding(&self, wrapped: &[u8]) -> Result<Vec<u8>, AESError> {
+        if wrapped.len() < 16 || !wrapped.len().is_multiple_of(8) {
+            return Err(AESError::InvalidLength);
+        }
+
+        let (iv, mut key) = match wrapped.len() {
+            16 => {
+                let mut block = [0; 16];
+                block.copy_from_slice(wrapped);
+                self.kek.decrypt_block(&mut block);
+
+                let mut iv = [0; 8];
+                iv.copy_from_slice(&block[..8]);
+                (iv, block[8..].to_vec())
+            }
+            _ => self.unwrap_blocks(wrapped),
+        };
+
+        let length = u32::from_be_bytes([iv[4], iv[5], iv[6], iv[7]]) as usize;
+        let padding_valid = length <= key.len()
+            && length + 8 > key.len()
+            && key[length.min(key.len())..].iter().all(|byte| *byte == 0);
+
+        if !constant_time_eq(&iv[..4], &PADDING_IV) || !padding_valid {
+            return Err(AESError::AuthenticationFailed);
+        }
+
+        key.truncate(length);
+        Ok(key)
+    }
+
+    fn wrap_blocks(&self, iv: [u8; 8], key: &[u8]) -> Vec<u8> {
+        let mut a = iv;
+        let mut r: Vec<[u8; 8]> = key
+            .chunks(8)
+            .map(|chunk| {
+                let mut block = [0; 8];
+                block.copy_from_slice(chunk);
+                block
+            })
+            .collect();
+        let n = r.len() as u64;
+
+        for j in 0..6 {
+            for (i, register) in r.iter_mut().enumerate() {
+                let mut block = [0; 16];
+                block[..8].copy_from_slice(&a);
+                block[8..].copy_from_slice(register);
+                self.kek.encrypt_block(&mut block);
+
+                let t = n * j + i as u64 + 1;
+                a.copy_from_slice(&block[..8]);
+                for (byte, t) in a.iter_mut().zip(t.to_be_bytes()) {
+                    *byte ^= t;
+                }
+                register.copy_from_slice(&block[8..]);
+            }
+        }
+
+        let mut wrapped = a.to_vec();
+        for register in r {
+            wrapped.extend_from_slice(&register);
+        }
+
+        wrapped
+    }
+
+    fn unwrap_blocks(&self, wrapped: &[u8]) -> ([u8; 8], Vec<u8>) {
+        let mut a = [0; 8];
+        a.copy_from_slice(&wrapped[..8]);
+        let mut r: Vec<[u8; 8]> = wrapped[8..]
+            .chunks(8)
+            .map(|chunk| {
+                let mut block = [0; 8];
+                block.copy_from_slice(chunk);
+                block
+            })
+            .collect();
+        let n = r.len() as u64;
+
+        for j in (0..6).rev() {
+            for (i, register) in r.iter_mut().enumerate().rev() {
+                let t = n * j + i as u64 + 1;
+                for (byte, t) in a.iter_mut().zip(t.to_be_bytes()) {
+                    *byte ^= t;
+                }
+
+                let mut block = [0; 16];
+                block[..8].copy_from_slice(&a);
+                block[8..].copy_from_slice(register);
+                self.kek.decrypt_block(&mut block);
+
+                a.copy_from_slice(&block[..8]);
+                register.copy_from_slice(&block[8..]);
+            }
+        }
+
+        (a, r.concat())
+    }
+}
@@ -0,0 +1,42 @@
+use crate::cryptography::aes::AES;
+use crate::cryptography::CryptographicAlgorithm;
+
+pub struct OFB {
+    aes: AES,
+    iv: [u8; 16],
+}
+
+impl OFB {
+    pub fn new(aes: AES, iv: [u8; 16]) -> Self {
+        OFB { aes, iv }
+    }
+
+    pub fn iv(&self) -> [u8; 16] {
+        self.iv
+    }
+
+    fn apply_keystream(&self, input: &[u8]) -> Vec<u8> {
+        let mut output = Vec::with_capacity(input.len());
+        let mut keystream = self.iv;
+
+        for slice in input.chunks(16) {
+            self.aes.encrypt_block(&mut keystream);
+
+            for (byte, key) in slice.iter().zip(keystream) {
+                output.push(byte ^ key);
+            }
+        }
+
+        output
+    }
+}
+
+impl CryptographicAlgorithm for OFB {
+    fn encrypt(&self, message: &[u8]) -> Vec<u8> {
+        self.apply_keystream(message)
+    }
+
+    fn decrypt(&self, cipher: &[u8]) -> Vec<u8> {
+        self.apply_keystream(cipher)
+    }
+}
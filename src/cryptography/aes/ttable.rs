@@ -0,0 +1,200 @@
+use crate::cryptography::aes::constant_time::reverse_mix_column;
+use crate::cryptography::aes::{INVERSE_SBOX, SBOX};
+
+// Each round is done on 32-bit columns, SubBytes and MixColumns are merged into
+// lookups in four tables that only differ by a byte rotation
+static ENCRYPTION_TABLES: [[u32; 256]; 4] = encryption_tables();
+static DECRYPTION_TABLES: [[u32; 256]; 4] = decryption_tables();
+
+const fn xtime(byte: u8) -> u8 {
+    (byte << 1) ^ (0x1b * (byte >> 7))
+}
+
+const fn multiply(mut a: u8, mut b: u8) -> u8 {
+    let mut product = 0;
+
+    while b != 0 {
+        if b & 1 == 1 {
+            product ^= a;
+        }
+        a = xtime(a);
+        b >>= 1;
+    }
+
+    product
+}
+
+const fn column(bytes: [u8; 4]) -> u32 {
+    u32::from_be_bytes(bytes)
+}
+
+const fn rotations(table: [u32; 256]) -> [[u32; 256]; 4] {
+    let mut tables = [table; 4];
+    let mut i = 0;
+
+    while i < 256 {
+        tables[1][i] = table[i].rotate_right(8);
+        tables[2][i] = table[i].rotate_right(16);
+        tables[3][i] = table[i].rotate_right(24);
+        i += 1;
+    }
+
+    tables
+}
+
+const fn encryption_tables() -> [[u32; 256]; 4] {
+    let mut table = [0; 256];
+    let mut i = 0;
+
+    while i < 256 {
+        let s = SBOX[i];
+        table[i] = column([multiply(s, 2), s, s, multiply(s, 3)]);
+        i += 1;
+    }
+
+    rotations(table)
+}
+
+const fn decryption_tables() -> [[u32; 256]; 4] {
+    let mut table = [0; 256];
+    let mut i = 0;
+
+    while i < 256 {
+        let s = INVERSE_SBOX[i];
+        table[i] = column([
+            multiply(s, 14),
+            multiply(s, 9),
+            multiply(s, 13),
+            multiply(s, 11),
+        ]);
+        i += 1;
+    }
+
+    rotations(table)
+}
+
+fn load(matrix: &[u8; 16]) -> [u32; 4] {
+    let mut state = [0; 4];
+    for (word, bytes) in state.iter_mut().zip(matrix.chunks(4)) {
+        *word = u32::from_be_bytes(bytes.try_into().unwrap());
+    }
+    state
+}
+
+fn store(state: [u32; 4], matrix: &mut [u8; 16]) {
+    for (bytes, word) in matrix.chunks_mut(4).zip(state) {
+        bytes.copy_from_slice(&word.to_be_bytes());
+    }
+}
+
+fn round_key(key: &[u8], round: usize) -> [u32; 4] {
+    let mut words = [0; 16];
+    words.copy_from_slice(&key[round * 16..round * 16 + 16]);
+    load(&words)
+}
+
+fn byte(word: u32, index: usize) -> usize {
+    (word >> (24 - 8 * index)) as usize & 0xff
+}
+
+// The columns feeding each output column, ShiftRows going left or right
+fn round(tables: &[[u32; 256]; 4], state: [u32; 4], key: [u32; 4], shift: [usize; 4]) -> [u32; 4] {
+    let mut output = [0; 4];
+
+    for (c, word) in output.iter_mut().enumerate() {
+        *word = key[c];
+        for (row, table) in tables.iter().enumerate() {
+            *word ^= table[byte(state[(c + shift[row]) % 4], row)];
+        }
+    }
+
+    output
+}
+
+fn final_round(sbox: &[u8; 256], state: [u32; 4], key: [u32; 4], shift: [usize; 4]) -> [u32; 4] {
+    let mut output = [0; 4];
+
+    for (c, word) in output.iter_mut().enumerate() {
+        let mut bytes = [0; 4];
+        for (row, byte_out) in bytes.iter_mut().enumerate() {
+            *byte_out = sbox[byte(state[(c + shift[row]) % 4], row)];
+        }
+        *word = u32::from_be_bytes(bytes) ^ key[c];
+    }
+
+    output
+}
+
+const ENCRYPTION_SHIFT: [usize; 4] = [0, 1, 2, 3];
+const DECRYPTION_SHIFT: [usize; 4] = [0, 3, 2, 1];
+
+pub(super) fn encrypt_block(key: &[u8], matrix: &mut [u8; 16]) {
+    let rounds = key.len() / 16 - 1;
+
+    let mut state = load(matrix);
+    for (word, key) in state.iter_mut().zip(round_key(key, 0)) {
+        *word ^= key;
+    }
+
+    for r in 1..rounds {
+        state = round(
+            &ENCRYPTION_TABLES,
+            state,
+            round_key(key, r),
+            ENCRYPTION_SHIFT,
+        );
+    }
+
+    state = final_round(&SBOX, state, round_key(key, rounds), ENCRYPTION_SHIFT);
+    store(state, matrix);
+}
+
+// Expects the key schedule from `decryption_key`
+pub(super) fn decrypt_block(key: &[u8], matrix: &mut [u8; 16]) {
+    let rounds = key.len() / 16 - 1;
+
+    let mut state = load(matrix);
+    for (word, key) in state.iter_mut().zip(round_key(key, 0)) {
+        *word ^= key;
+    }
+
+    for r in 1..rounds {
+        state = round(
+            &DECRYPTION_TABLES,
+            state,
+            round_key(key, r),
+            DECRYPTION_SHIFT,
+        );
+    }
+
+    state = final_round(
+        &INVERSE_SBOX,
+        state,
+        round_key(key, rounds),
+        DECRYPTION_SHIFT,
+    );
+    store(state, matrix);
+}
+
+// Key schedule for the equivalent inverse cipher (FIPS 197 section 5.3.5): the round
+// keys in reverse order with InvMixColumns applied to all but the first and last
+pub(super) fn decryption_key<const N: usize>(key: &[u8; N]) -> [u8; N] {
+    let rounds = N / 16 - 1;
+    let mut decryption_key = [0; N];
+
+    for r in 0..=rounds {
+        let mut words = round_key(key, rounds - r);
+
+        if r != 0 && r != rounds {
+            for word in words.iter_mut() {
+                *word = reverse_mix_column(*word);
+            }
+        }
+
+        for (i, word) in words.iter().enumerate() {
+            decryption_key[r * 16 + i * 4..r * 16 + i * 4 + 4].copy_from_slice(&word.to_be_bytes());
+        }
+    }
+
+    decryption_key
+}
@@ -0,0 +1,123 @@
+use crate::cryptography::aes::{AESBackend, AESError, InitKey, AES};
+use crate::cryptography::constant_time_eq;
+
+// The combined key is the data key followed by the tweak key
+pub struct XTS {
+    data: AES,
+    tweak: AES,
+}
+
+impl XTS {
+    pub fn new(key: &[u8]) -> Result<Self, AESError> {
+        XTS::with_backend(key, AESBackend::detect())
+    }
+
+    pub fn with_backend(key: &[u8], backend: AESBackend) -> Result<Self, AESError> {
+        if key.len() != 32 && key.len() != 64 {
+            return Err(AESError::InccorectSize);
+        }
+
+        // IEEE 1619 and SP 800-38E require the two halves to differ
+        let (data, tweak) = key.split_at(key.len() / 2);
+        if constant_time_eq(data, tweak) {
+            return Err(AESError::InvalidKey);
+        }
+
+        Ok(XTS {
+            data: AES::with_backend(InitKey::from_slice(data)?, backend),
+            tweak: AES::with_backend(InitKey::from_slice(tweak)?, backend),
+        })
+    }
+
+    pub fn backend(&self) -> AESBackend {
+        self.data.backend()
+    }
+
+    pub fn encrypt_sector(&self, sector: u128, data: &[u8]) -> Result<Vec<u8>, AESError> {
+        self.process(sector, data, false)
+    }
+
+    pub fn decrypt_sector(&self, sector: u128, data: &[u8]) -> Result<Vec<u8>, AESError> {
+        self.process(sector, data, true)
+    }
+
+    fn process(&self, sector: u128, data: &[u8], decrypt: bool) -> Result<Vec<u8>, AESError> {
+        if data.len() < 16 {
+            return Err(AESError::InvalidLength);
+        }
+
+        let mut tweak = sector.to_le_bytes();
+        self.tweak.encrypt_block(&mut tweak);
+
+        let full_blocks = data.len() / 16;
+        let remainder = data.len() % 16;
+
+        // With a partial final block the last full block is handled by ciphertext stealing
+        let direct_blocks = match remainder {
+            0 => full_blocks,
+            _ => full_blocks - 1,
+        };
+
+        let mut output = Vec::with_capacity(data.len());
+        for slice in data[..direct_blocks * 16].chunks(16) {
+            let mut block = [0; 16];
+            block.copy_from_slice(slice);
+            self.process_block(&mut block, &tweak, decrypt);
+
+            output.extend_from_slice(&block);
+            multiply_by_alpha(&mut tweak);
+        }
+
+        if remainder == 0 {
+            return Ok(output);
+        }
+
+        let mut last_tweak = tweak;
+        multiply_by_alpha(&mut last_tweak);
+
+        // Decryption uses the tweaks of the last two blocks in the opposite order
+        let (first_tweak, second_tweak) = match decrypt {
+            false => (tweak, last_tweak),
+            true => (last_tweak, tweak),
+        };
+
+        let mut block = [0; 16];
+        block.copy_from_slice(&data[direct_blocks * 16..full_blocks * 16]);
+        self.process_block(&mut block, &first_tweak, decrypt);
+
+        let tail = block[..remainder].to_vec();
+        block[..remainder].copy_from_slice(&data[full_blocks * 16..]);
+        self.process_block(&mut block, &second_tweak, decrypt);
+
+        output.extend_from_slice(&block);
+        output.extend_from_slice(&tail);
+
+        Ok(output)
+    }
+
+    fn process_block(&self, block: &mut [u8; 16], tweak: &[u8; 16], decrypt: bool) {
+        for (byte, t) in block.iter_mut().zip(tweak) {
+            *byte ^= t;
+        }
+
+        match decrypt {
+            false => self.data.encrypt_block(block),
+            true => self.data.decrypt_block(block),
+        }
+
+        for (byte, t) in block.iter_mut().zip(tweak) {
+            *byte ^= t;
+        }
+    }
+}
+
+// Multiplication by x in GF(2^128) with the little-endian byte order of IEEE 1619
+fn multiply_by_alpha(tweak: &mut [u8; 16]) {
+    let value = u128::from_le_bytes(*tweak);
+    let carry = (value >> 127) as u8;
+
+    let mut value = (value << 1).to_le_bytes();
+    value[0] ^= 0x87 & carry.wrapping_neg();
+
+    *tweak = value;
+}
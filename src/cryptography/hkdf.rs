@@ -0,0 +1,78 @@
+use crate::cryptography::aes::InitKey;
+use crate::cryptography::hmac::HMAC;
+use crate::cryptography::HashingAlgorithm;
+
+#[derive(Debug)]
+pub enum HKDFError {
+    OutputTooLong,
+    InvalidKeySize,
+    InvalidHash,
+}
+
+pub struct HKDF<H: HashingAlgorithm + Clone> {
+    hash: H,
+}
+
+impl<H: HashingAlgorithm + Clone> HKDF<H> {
+    pub fn new(hash: H) -> Self {
+        HKDF { hash }
+    }
+
+    pub fn extract(&self, salt: &[u8], ikm: &[u8]) -> Result<Vec<u8>, HKDFError> {
+        // An absent salt is replaced by a string of zeros the length of the hash output
+        let zeros = vec![0; self.hash.output_size()];
+        let salt = if salt.is_empty() { &zeros } else { salt };
+
+        let hmac = HMAC::new(self.hash.clone(), salt).map_err(|_| HKDFError::InvalidHash)?;
+        Ok(hmac.mac(ikm))
+    }
+
+    pub fn expand(&self, prk: &[u8], info: &[u8], length: usize) -> Result<Vec<u8>, HKDFError> {
+        if length > 255 * self.hash.output_size() {
+            return Err(HKDFError::OutputTooLong);
+        }
+
+        let hmac = HMAC::new(self.hash.clone(), prk).map_err(|_| HKDFError::InvalidHash)?;
+        let mut okm = Vec::with_capacity(length);
+        let mut t: Vec<u8> = Vec::new();
+        let mut counter: u8 = 1;
+
+        while okm.len() < length {
+            t.extend_from_slice(info);
+            t.push(counter);
+            t = hmac.mac(&t);
+
+            okm.extend_from_slice(&t);
+            counter = counter.wrapping_add(1);
+        }
+
+        okm.truncate(length);
+        Ok(okm)
+    }
+
+    pub fn derive(
+        &self,
+        salt: &[u8],
+        ikm: &[u8],
+        info: &[u8],
+        length: usize,
+    ) -> Result<Vec<u8>, HKDFError> {
+        let prk = self.extract(salt, ikm)?;
+        self.expand(&prk, info, length)
+    }
+
+    pub fn derive_init_key(
+        &self,
+        salt: &[u8],
+        ikm: &[u8],
+        info: &[u8],
+        key_size: usize,
+    ) -> Result<InitKey, HKDFError> {
+        if ![16, 24, 32].contains(&key_size) {
+            return Err(HKDFError::InvalidKeySize);
+        }
+
+        let okm = self.derive(salt, ikm, info, key_size)?;
+        InitKey::from_slice(&okm).map_err(|_| HKDFError::InvalidKeySize)
+    }
+}
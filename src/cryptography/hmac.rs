@@ -0,0 +1,52 @@
+use crate::cryptography::{constant_time_eq, HashingAlgorithm};
+
+#[derive(Debug)]
+pub enum HMACError {
+    InvalidHash,
+}
+
+const IPAD: u8 = 0x36;
+const OPAD: u8 = 0x5c;
+
+pub struct HMAC<H: HashingAlgorithm> {
+    hash: H,
+    key: Vec<u8>,
+}
+
+impl<H: HashingAlgorithm> HMAC<H> {
+    // The hashed key has to fit in a block, so the output may not be longer than one
+    pub fn new(hash: H, key: &[u8]) -> Result<Self, HMACError> {
+        let block_size = hash.block_size();
+        if hash.output_size() == 0 || hash.output_size() > block_size {
+            return Err(HMACError::InvalidHash);
+        }
+
+        // Keys longer than the block size are hashed first, shorter keys are zero padded
+        let mut key = if key.len() > block_size {
+            hash.hash(key.to_vec())
+        } else {
+            key.to_vec()
+        };
+        key.resize(block_size, 0);
+
+        Ok(HMAC { hash, key })
+    }
+
+    pub fn output_size(&self) -> usize {
+        self.hash.output_size()
+    }
+
+    pub fn mac(&self, message: &[u8]) -> Vec<u8> {
+        let mut inner: Vec<u8> = self.key.iter().map(|byte| byte ^ IPAD).collect();
+        inner.extend_from_slice(message);
+        let inner = self.hash.hash(inner);
+
+        let mut outer: Vec<u8> = self.key.iter().map(|byte| byte ^ OPAD).collect();
+        outer.extend_from_slice(&inner);
+        self.hash.hash(outer)
+    }
+
+    pub fn verify(&self, message: &[u8], tag: &[u8]) -> bool {
+        constant_time_eq(&self.mac(message), tag)
+    }
+}
@@ -0,0 +1,81 @@
+use crate::cryptography::aes::InitKey;
+use crate::cryptography::hmac::HMAC;
+use crate::cryptography::HashingAlgorithm;
+
+#[derive(Debug)]
+pub enum PBKDF2Error {
+    InvalidIterationCount,
+    OutputTooLong,
+    InvalidKeySize,
+    InvalidHash,
+}
+
+pub struct PBKDF2<H: HashingAlgorithm + Clone> {
+    hash: H,
+    iterations: u32,
+}
+
+impl<H: HashingAlgorithm + Clone> PBKDF2<H> {
+    pub fn new(hash: H, iterations: u32) -> Self {
+        PBKDF2 { hash, iterations }
+    }
+
+    pub fn iterations(&self) -> u32 {
+        self.iterations
+    }
+
+    pub fn derive(
+        &self,
+        password: &[u8],
+        salt: &[u8],
+        length: usize,
+    ) -> Result<Vec<u8>, PBKDF2Error> {
+        if self.iterations == 0 {
+            return Err(PBKDF2Error::InvalidIterationCount);
+        }
+
+        // Also rejects hashes with an empty output before it is used as a divisor
+        let hmac = HMAC::new(self.hash.clone(), password).map_err(|_| PBKDF2Error::InvalidHash)?;
+
+        // RFC 8018 caps the output at (2^32 - 1) blocks, the block index is a u32
+        let blocks = length.div_ceil(hmac.output_size());
+        if blocks > u32::MAX as usize {
+            return Err(PBKDF2Error::OutputTooLong);
+        }
+
+        let mut key = Vec::with_capacity(length);
+        for block in 1..=blocks as u32 {
+            // U_1 = PRF(P, S || INT(i)), U_j = PRF(P, U_{j-1}), T_i = U_1 ^ ... ^ U_c
+            let mut u = salt.to_vec();
+            u.extend_from_slice(&block.to_be_bytes());
+            u = hmac.mac(&u);
+
+            let mut t = u.clone();
+            for _ in 1..self.iterations {
+                u = hmac.mac(&u);
+                for (t, u) in t.iter_mut().zip(&u) {
+                    *t ^= u;
+                }
+            }
+
+            key.append(&mut t);
+        }
+
+        key.truncate(length);
+        Ok(key)
+    }
+
+    pub fn derive_init_key(
+        &self,
+        password: &[u8],
+        salt: &[u8],
+        key_size: usize,
+    ) -> Result<InitKey, PBKDF2Error> {
+        if ![16, 24, 32].contains(&key_size) {
+            return Err(PBKDF2Error::InvalidKeySize);
+        }
+
+        let key = self.derive(password, salt, key_size)?;
+        InitKey::from_slice(&key).map_err(|_| PBKDF2Error::InvalidKeySize)
+    }
+}
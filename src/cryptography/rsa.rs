@@ -1,12 +1,23 @@
-extern crate rand;
-use rand::Rng;
+use crate::cryptography::HashingAlgorithm;
+use crate::math::{generate_prime, BigUint};
 
-use crate::math::{exponential_modulus, gcd};
+mod oaep;
+mod pkcs1v15;
+
+pub use oaep::OAEP;
+pub use pkcs1v15::PKCS1v15;
+
+#[derive(Debug)]
+pub enum RSAError {
+    InvalidKeySize,
+    MessageTooLong,
+    DecryptionError,
+}
 
 pub struct RSA {
-    pub n: u128,
-    pub e: u128,
-    d: u128
+    pub n: BigUint,
+    pub e: BigUint,
+    d: BigUint
 }
 
 impl Default for RSA {
@@ -17,63 +28,114 @@ impl Default for RSA {
 
 impl RSA {
     pub fn new() -> Self {
-        let primes: [u128; 2] = [
-            834546085180674575058629332681,
-            107338188804765057603465338413,
-        ];
-        let p = primes[rand::thread_rng().gen_range(0..primes.len())];
-        let mut q;
+        RSA::generate(2048).unwrap()
+    }
+
+    // Random key with a modulus of exactly `bits` bits and e = 65537
+    pub fn generate(bits: usize) -> Result<Self, RSAError> {
+        if bits < 512 {
+            return Err(RSAError::InvalidKeySize);
+        }
+
+        let e = BigUint::from_u128(65537);
+        let one = BigUint::one();
+
         loop {
-            q = primes[rand::thread_rng().gen_range(0..primes.len())];
-            if q != p {
-                break;
+            let p = generate_prime(bits - bits / 2);
+            let q = generate_prime(bits / 2);
+
+            // FIPS 186-5 appendix A.1.3 requires |p - q| > 2^(bits / 2 - 100)
+            let difference = if p > q { &p - &q } else { &q - &p };
+            if difference <= &BigUint::one() << (bits / 2 - 100) {
+                continue;
             }
-        }
-        let n = p * q;
-        let t = (p - 1) * (q - 1);
 
-        let e: u128 = 65537;
-        let mut x = 0;
-        let mut y = 0;
-        gcd(e as i128, t as i128, &mut x, &mut y);
-        let d: u128 = (t as i128 + x) as u128;
+            let t = &(&p - &one) * &(&q - &one);
+            if e.gcd(&t) != one {
+                continue;
+            }
 
-        RSA {
-            n,
-            e,
-            d
+            let d = e.mod_inverse(&t).unwrap();
+
+            return Ok(RSA {
+                n: &p * &q,
+                e,
+                d
+            });
         }
     }
 
-    pub fn from_num(n: u128, e: u128, d: u128) -> Self {
-        RSA {
+    pub fn from_num(n: u128, e: u128, d: u128) -> Result<Self, RSAError> {
+        RSA::from_components(BigUint::from_u128(n), BigUint::from_u128(e), BigUint::from_u128(d))
+    }
+
+    // The modulus must be odd and larger than one for the Montgomery ladder in decryption
+    pub fn from_components(n: BigUint, e: BigUint, d: BigUint) -> Result<Self, RSAError> {
+        if !n.is_odd() || n == BigUint::one() {
+            return Err(RSAError::InvalidKeySize);
+        }
+
+        Ok(RSA {
             n,
             e,
             d
-        }
+        })
+    }
+
+    pub fn bits(&self) -> usize {
+        self.n.bits()
+    }
+
+    // Length of the modulus in bytes
+    pub fn size(&self) -> usize {
+        self.bits().div_ceil(8)
+    }
+
+    // RSAEP and RSADP, the bare operations without padding. Textbook RSA is
+    // deterministic and malleable, encrypt messages with OAEP or PKCS1v15 instead
+    pub fn encrypt_integer(&self, m: &BigUint) -> BigUint {
+        m.modpow(&self.e, &self.n)
+    }
+
+    pub fn decrypt_integer(&self, c: &BigUint) -> BigUint {
+        c.modpow_constant_time(&self.d, &self.n)
     }
 }
 
+// Per-byte textbook RSA kept for existing callers, each byte is a separate
+// deterministic ciphertext so equal bytes encrypt alike
 impl RSA {
-    pub fn encrypt(&self, message: Vec<u8>) -> Vec<u128> {
-        let mut cipher: Vec<u128> = Vec::new();
-
-        for m in message {
-            cipher.push(exponential_modulus(m as u128, self.e, self.n));
-        }
+    #[deprecated(note = "textbook RSA on single bytes is insecure, use OAEP or PKCS1v15")]
+    pub fn encrypt(&self, message: Vec<u8>) -> Vec<BigUint> {
+        message
+            .into_iter()
+            .map(|m| self.encrypt_integer(&BigUint::from_u128(m as u128)))
+            .collect()
+    }
 
+    #[deprecated(note = "textbook RSA on single bytes is insecure, use OAEP or PKCS1v15")]
+    pub fn decrypt(&self, cipher: Vec<BigUint>) -> Vec<u8> {
         cipher
+            .iter()
+            .map(|c| *self.decrypt_integer(c).to_be_bytes().last().unwrap())
+            .collect()
     }
+}
 
-    pub fn decrypt(&self, cipher: Vec<u128>) -> Vec<u8> {
-        let mut message: Vec<u8> = Vec::new();
-
-        for c in cipher {
-            message.push(exponential_modulus(c, self.d, self.n) as u8);
-        }
+// Mask generation function from RFC 8017 appendix B.2.1
+fn mgf1<H: HashingAlgorithm>(hash: &H, seed: &[u8], length: usize) -> Vec<u8> {
+    let mut mask = Vec::with_capacity(length + hash.output_size());
+    let mut counter: u32 = 0;
 
-        message
+    while mask.len() < length {
+        let mut input = seed.to_vec();
+        input.extend_from_slice(&counter.to_be_bytes());
+        mask.extend(hash.hash(input));
+        counter += 1;
     }
+
+    mask.truncate(length);
+    mask
 }
 
 // impl CryptographicAlgorithm for RSA {
@@ -0,0 +1,117 @@
+extern crate rand;
+use rand::Rng;
+
+use crate::cryptography::rsa::{mgf1, RSAError, RSA};
+use crate::cryptography::{constant_time_eq, HashingAlgorithm};
+use crate::math::BigUint;
+
+// RSAES-OAEP from RFC 8017 section 7.1, MGF1 uses the same hash as the label
+pub struct OAEP<H: HashingAlgorithm> {
+    rsa: RSA,
+    hash: H,
+    label: Vec<u8>,
+}
+
+impl<H: HashingAlgorithm> OAEP<H> {
+    pub fn new(rsa: RSA, hash: H, label: &[u8]) -> Result<Self, RSAError> {
+        if rsa.size() < 2 * hash.output_size() + 2 {
+            return Err(RSAError::InvalidKeySize);
+        }
+
+        Ok(OAEP {
+            rsa,
+            hash,
+            label: label.to_vec(),
+        })
+    }
+
+    pub fn max_message_length(&self) -> usize {
+        self.rsa.size() - 2 * self.hash.output_size() - 2
+    }
+
+    pub fn encrypt(&self, message: &[u8]) -> Result<Vec<u8>, RSAError> {
+        if message.len() > self.max_message_length() {
+            return Err(RSAError::MessageTooLong);
+        }
+
+        let k = self.rsa.size();
+        let h_len = self.hash.output_size();
+
+        // DB = lHash || PS || 0x01 || M
+        let mut db = self.hash.hash(self.label.clone());
+        db.resize(k - h_len - 2 - message.len(), 0);
+        db.push(0x01);
+        db.extend_from_slice(message);
+
+        let mut seed = vec![0; h_len];
+        rand::thread_rng().fill(&mut seed[..]);
+
+        for (byte, mask) in db.iter_mut().zip(mgf1(&self.hash, &seed, k - h_len - 1)) {
+            *byte ^= mask;
+        }
+        for (byte, mask) in seed.iter_mut().zip(mgf1(&self.hash, &db, h_len)) {
+            *byte ^= mask;
+        }
+
+        let mut encoded = vec![0];
+        encoded.extend_from_slice(&seed);
+        encoded.extend_from_slice(&db);
+
+        let cipher = self.rsa.encrypt_integer(&BigUint::from_be_bytes(&encoded));
+        Ok(cipher.to_be_bytes_padded(k).unwrap())
+    }
+
+    // Every padding failure gives the same error after the same amount of work
+    pub fn decrypt(&self, cipher: &[u8]) -> Result<Vec<u8>, RSAError> {
+        let k = self.rsa.size();
+        let h_len = self.hash.output_size();
+
+        if cipher.len() != k {
+            return Err(RSAError::DecryptionError);
+        }
+        let cipher = BigUint::from_be_bytes(cipher);
+        if cipher >= self.rsa.n {
+            return Err(RSAError::DecryptionError);
+        }
+
+        let encoded = self
+            .rsa
+            .decrypt_integer(&cipher)
+            .to_be_bytes_padded(k)
+            .unwrap();
+
+        let (y, rest) = encoded.split_at(1);
+        let (masked_seed, masked_db) = rest.split_at(h_len);
+
+        let mut seed = masked_seed.to_vec();
+        for (byte, mask) in seed.iter_mut().zip(mgf1(&self.hash, masked_db, h_len)) {
+            *byte ^= mask;
+        }
+        let mut db = masked_db.to_vec();
+        for (byte, mask) in db.iter_mut().zip(mgf1(&self.hash, &seed, k - h_len - 1)) {
+            *byte ^= mask;
+        }
+
+        let label_hash = self.hash.hash(self.label.clone());
+        let mut valid = constant_time_eq(&db[..h_len], &label_hash) as u8 & (y[0] == 0) as u8;
+
+        // Find the 0x01 after the zero padding without branching on the bytes
+        let mut found = 0u8;
+        let mut index = 0;
+        for (i, byte) in db.iter().enumerate().skip(h_len) {
+            let is_one = (*byte == 0x01) as u8;
+            let is_zero = (*byte == 0x00) as u8;
+
+            index |= i * (is_one & !found & 1) as usize;
+            valid &= found | is_one | is_zero;
+            found |= is_one;
+        }
+        valid &= found;
+
+        if valid == 0 {
+            return Err(RSAError::DecryptionError);
+        }
+
+        Ok(db[index + 1..].to_vec())
+    }
+}
@@ -0,0 +1,151 @@
+extern crate rand;
+use rand::Rng;
+
+use crate::cryptography::hmac::HMAC;
+use crate::cryptography::rsa::{RSAError, RSA};
+use crate::cryptography::sha::SHA;
+use crate::cryptography::HashingAlgorithm;
+use crate::math::BigUint;
+
+// Tries at picking a synthetic message length below the maximum
+const LENGTH_CANDIDATES: usize = 128;
+
+// RSAES-PKCS1-v1_5 from RFC 8017 section 7.2. Decryption uses implicit rejection
+// as in draft-irtf-cfrg-rsa-guidance and OpenSSL 3.2: a ciphertext with bad
+// padding decrypts to a pseudorandom message derived from the ciphertext and the
+// private key instead of failing, so the result is no Bleichenbacher oracle.
+pub struct PKCS1v15 {
+    rsa: RSA,
+}
+
+impl PKCS1v15 {
+    pub fn new(rsa: RSA) -> Result<Self, RSAError> {
+        if rsa.size() < 11 {
+            return Err(RSAError::InvalidKeySize);
+        }
+
+        Ok(PKCS1v15 { rsa })
+    }
+
+    pub fn max_message_length(&self) -> usize {
+        self.rsa.size() - 11
+    }
+
+    pub fn encrypt(&self, message: &[u8]) -> Result<Vec<u8>, RSAError> {
+        if message.len() > self.max_message_length() {
+            return Err(RSAError::MessageTooLong);
+        }
+
+        let k = self.rsa.size();
+        let mut rng = rand::thread_rng();
+
+        // EM = 0x00 || 0x02 || PS || 0x00 || M with PS random and nonzero
+        let mut encoded = vec![0x00, 0x02];
+        encoded.extend((0..k - message.len() - 3).map(|_| rng.gen_range(1..=255u8)));
+        encoded.push(0x00);
+        encoded.extend_from_slice(message);
+
+        let cipher = self.rsa.encrypt_integer(&BigUint::from_be_bytes(&encoded));
+        Ok(cipher.to_be_bytes_padded(k).unwrap())
+    }
+
+    // Only fails for ciphertexts that are invalid regardless of the key, when the
+    // length is wrong or the value is not below the modulus
+    pub fn decrypt(&self, cipher: &[u8]) -> Result<Vec<u8>, RSAError> {
+        let k = self.rsa.size();
+
+        if cipher.len() != k {
+            return Err(RSAError::DecryptionError);
+        }
+        let c = BigUint::from_be_bytes(cipher);
+        if c >= self.rsa.n {
+            return Err(RSAError::DecryptionError);
+        }
+
+        let encoded = self.rsa.decrypt_integer(&c).to_be_bytes_padded(k).unwrap();
+        let (synthetic, synthetic_length) = self.synthetic_message(cipher);
+
+        let mut good = (encoded[0] == 0x00) as usize & (encoded[1] == 0x02) as usize;
+
+        // Index of the first zero byte after the padding, found without branching
+        let mut found = 0;
+        let mut zero_index = 0;
+        for (i, byte) in encoded.iter().enumerate().skip(2) {
+            let is_zero = (*byte == 0) as usize;
+            zero_index = select(is_zero & !found & 1, i, zero_index);
+            found |= is_zero;
+        }
+
+        // PS is at least eight bytes, this also fails when no zero byte was found
+        good &= (zero_index >= 10) as usize;
+
+        // Both candidates are suffixes of a k byte buffer, so pick bytewise and cut
+        let length = select(good, k - zero_index - 1, synthetic_length);
+        let mask = (good as u8).wrapping_neg();
+        let message: Vec<u8> = encoded
+            .iter()
+            .zip(&synthetic)
+            .map(|(real, fake)| (real & mask) | (fake & !mask))
+            .collect();
+
+        Ok(message[k - length..].to_vec())
+    }
+
+    // The k byte synthetic message and how much of its tail to return
+    fn synthetic_message(&self, cipher: &[u8]) -> (Vec<u8>, usize) {
+        let k = self.rsa.size();
+
+        let d = self.rsa.d.to_be_bytes_padded(k).unwrap();
+        let kdk = HMAC::new(SHA::new(), &SHA::new().hash(d))
+            .unwrap()
+            .mac(cipher);
+
+        let synthetic = prf(&kdk, b"message", k);
+        let candidates = prf(&kdk, b"length", 2 * LENGTH_CANDIDATES);
+
+        // The last candidate below the maximum message length wins
+        let max_length = k - 10;
+        let mut mask = max_length;
+        for shift in [1, 2, 4, 8] {
+            mask |= mask >> shift;
+        }
+
+        let mut synthetic_length = 0;
+        for candidate in candidates.chunks(2) {
+            let candidate = u16::from_be_bytes([candidate[0], candidate[1]]) as usize & mask;
+            synthetic_length = select(
+                (candidate < max_length) as usize,
+                candidate,
+                synthetic_length,
+            );
+        }
+
+        (synthetic, synthetic_length)
+    }
+}
+
+// HMAC-SHA256 in counter mode with the label and output size in bits as context
+fn prf(key: &[u8], label: &[u8], length: usize) -> Vec<u8> {
+    let hmac = HMAC::new(SHA::new(), key).unwrap();
+    let bits = (8 * length) as u16;
+
+    let mut output = Vec::with_capacity(length + hmac.output_size());
+    let mut counter: u16 = 0;
+    while output.len() < length {
+        let mut input = counter.to_be_bytes().to_vec();
+        input.extend_from_slice(label);
+        input.extend_from_slice(&bits.to_be_bytes());
+
+        output.extend(hmac.mac(&input));
+        counter += 1;
+    }
+
+    output.truncate(length);
+    output
+}
+
+// `a` when `condition` is 1 and `b` when it is 0
+fn select(condition: usize, a: usize, b: usize) -> usize {
+    let mask = condition.wrapping_neg();
+    (a & mask) | (b & !mask)
+}
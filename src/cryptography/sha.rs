@@ -1,12 +1,85 @@
-use crate::cryptography::HashingAlgorithm;
+use crate::cryptography::{HashingAlgorithm, StreamingHashingAlgorithm};
 use crate::math::right_rotate;
 
+#[derive(Clone, Copy)]
 pub enum SHAType {
+    SHA224,
     SHA256,
+    SHA384,
+    SHA512,
+    SHA512_224,
+    SHA512_256,
 }
 
+impl SHAType {
+    fn block_size(&self) -> usize {
+        match self {
+            SHAType::SHA224 | SHAType::SHA256 => 64,
+            _ => 128,
+        }
+    }
+
+    fn output_size(&self) -> usize {
+        match self {
+            SHAType::SHA224 => 28,
+            SHAType::SHA256 => 32,
+            SHAType::SHA384 => 48,
+            SHAType::SHA512 => 64,
+            SHAType::SHA512_224 => 28,
+            SHAType::SHA512_256 => 32,
+        }
+    }
+
+    fn initial_state(&self) -> State {
+        match self {
+            SHAType::SHA224 => State::SHA256(SHA224_INIT),
+            SHAType::SHA256 => State::SHA256(SHA256_INIT),
+            SHAType::SHA384 => State::SHA512(SHA384_INIT),
+            SHAType::SHA512 => State::SHA512(SHA512_INIT),
+            SHAType::SHA512_224 => State::SHA512(SHA512_224_INIT),
+            SHAType::SHA512_256 => State::SHA512(SHA512_256_INIT),
+        }
+    }
+}
+
+#[derive(Clone)]
+enum State {
+    SHA256([u32; 8]),
+    SHA512([u64; 8]),
+}
+
+impl State {
+    fn compress(&mut self, chunk: &[u8]) {
+        match self {
+            State::SHA256(state) => sha256_compress(state, chunk),
+            State::SHA512(state) => sha512_compress(state, chunk),
+        }
+    }
+
+    fn to_be_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(64);
+        match self {
+            State::SHA256(state) => {
+                for h in state {
+                    bytes.extend_from_slice(&h.to_be_bytes());
+                }
+            }
+            State::SHA512(state) => {
+                for h in state {
+                    bytes.extend_from_slice(&h.to_be_bytes());
+                }
+            }
+        }
+        bytes
+    }
+}
+
+#[derive(Clone)]
 pub struct SHA {
     kind: SHAType,
+    state: State,
+    buffer: Vec<u8>,
+    length: u128,
 }
 
 impl Default for SHA {
@@ -17,130 +90,263 @@ impl Default for SHA {
 
 impl SHA {
     pub fn new() -> SHA {
+        SHA::from_type(SHAType::SHA256)
+    }
+
+    pub fn from_type(kind: SHAType) -> SHA {
         SHA {
-            kind: SHAType::SHA256,
+            kind,
+            state: kind.initial_state(),
+            buffer: Vec::with_capacity(kind.block_size()),
+            length: 0,
         }
     }
+
+    pub fn kind(&self) -> SHAType {
+        self.kind
+    }
 }
 
 impl HashingAlgorithm for SHA {
-    fn hash(&self, mut message: Vec<u8>) -> Vec<u8> {
-        match self.kind {
-            SHAType::SHA256 => {
-                // Defining variables
-                let mut h0: u32 = 0x6a09e667;
-                let mut h1: u32 = 0xbb67ae85;
-                let mut h2: u32 = 0x3c6ef372;
-                let mut h3: u32 = 0xa54ff53a;
-                let mut h4: u32 = 0x510e527f;
-                let mut h5: u32 = 0x9b05688c;
-                let mut h6: u32 = 0x1f83d9ab;
-                let mut h7: u32 = 0x5be0cd19;
-
-                let k: [u32; 64] = [
-                    0x428a2f98, 0x71374491, 0xb5c0fbcf, 0xe9b5dba5, 0x3956c25b, 0x59f111f1,
-                    0x923f82a4, 0xab1c5ed5, 0xd807aa98, 0x12835b01, 0x243185be, 0x550c7dc3,
-                    0x72be5d74, 0x80deb1fe, 0x9bdc06a7, 0xc19bf174, 0xe49b69c1, 0xefbe4786,
-                    0x0fc19dc6, 0x240ca1cc, 0x2de92c6f, 0x4a7484aa, 0x5cb0a9dc, 0x76f988da,
-                    0x983e5152, 0xa831c66d, 0xb00327c8, 0xbf597fc7, 0xc6e00bf3, 0xd5a79147,
-                    0x06ca6351, 0x14292967, 0x27b70a85, 0x2e1b2138, 0x4d2c6dfc, 0x53380d13,
-                    0x650a7354, 0x766a0abb, 0x81c2c92e, 0x92722c85, 0xa2bfe8a1, 0xa81a664b,
-                    0xc24b8b70, 0xc76c51a3, 0xd192e819, 0xd6990624, 0xf40e3585, 0x106aa070,
-                    0x19a4c116, 0x1e376c08, 0x2748774c, 0x34b0bcb5, 0x391c0cb3, 0x4ed8aa4a,
-                    0x5b9cca4f, 0x682e6ff3, 0x748f82ee, 0x78a5636f, 0x84c87814, 0x8cc70208,
-                    0x90befffa, 0xa4506ceb, 0xbef9a3f7, 0xc67178f2,
-                ];
-
-                // Pre-processing (Padding):
-                let mut num = (message.len() * 8).to_be_bytes();
-                let num = num.as_mut_slice();
-                let mut num = num.to_vec();
-
-                message.push(128);
-                while (message.len() + 8) % 64 != 0 {
-                    message.push(0);
-                }
+    fn hash(&self, message: Vec<u8>) -> Vec<u8> {
+        let mut sha = SHA::from_type(self.kind);
+        sha.update(&message);
+        sha.finalize()
+    }
 
-                message.append(&mut num);
-
-                // Process the message in successive 512-bit chunks:
-                let temp: Vec<Vec<u8>> = message.chunks(4).map(|s| s.into()).collect();
-                {
-                    let mut w: [u32; 64] = [0; 64];
-                    for i in 0..16 {
-                        let num = ((temp[i][0] as u32) << 24)
-                            + ((temp[i][1] as u32) << 16)
-                            + ((temp[i][2] as u32) << 8)
-                            + (temp[i][3] as u32);
-                        w[i] = num;
-                    }
-                    // Extend the first 16 words into the remaining 48 words w[16..63] of the message schedule array:
-                    for i in 16..64 {
-                        let s0 = right_rotate(w[i - 15], 7)
-                            ^ right_rotate(w[i - 15], 18)
-                            ^ (w[i - 15] >> 3);
-
-                        let s1 = right_rotate(w[i - 2], 17)
-                            ^ right_rotate(w[i - 2], 19)
-                            ^ (w[i - 2] >> 10);
-
-                        w[i] = ((w[i - 16] as u64 + s0 as u64 + w[i - 7] as u64 + s1 as u64)
-                            & u32::MAX as u64) as u32;
-                    }
-
-                    // Initialize working variables to current hash value:
-                    let mut a = h0;
-                    let mut b = h1;
-                    let mut c = h2;
-                    let mut d = h3;
-                    let mut e = h4;
-                    let mut f = h5;
-                    let mut g = h6;
-                    let mut h = h7;
-
-                    // Compression function main loop:
-                    for i in 0..64 {
-                        let s1 = right_rotate(e, 6) ^ right_rotate(e, 11) ^ right_rotate(e, 25);
-                        let ch = (e & f) ^ ((!e) & g);
-                        let temp1 = ((h as u64 + s1 as u64 + ch as u64 + k[i] as u64 + w[i] as u64)
-                            & u32::MAX as u64) as u32;
-                        let s0 = right_rotate(a, 2) ^ right_rotate(a, 13) ^ right_rotate(a, 22);
-                        let maj = (a & b) ^ (a & c) ^ (b & c);
-                        let temp2 = ((s0 as u64 + maj as u64) & u32::MAX as u64) as u32;
-
-                        h = g;
-                        g = f;
-                        f = e;
-                        e = ((d as u64 + temp1 as u64) & u32::MAX as u64) as u32;
-                        d = c;
-                        c = b;
-                        b = a;
-                        a = ((temp1 as u64 + temp2 as u64) & u32::MAX as u64) as u32;
-                    }
-
-                    // Add the compressed chunk to the current hash value:
-                    h0 = ((h0 as u64 + a as u64) & u32::MAX as u64) as u32;
-                    h1 = ((h1 as u64 + b as u64) & u32::MAX as u64) as u32;
-                    h2 = ((h2 as u64 + c as u64) & u32::MAX as u64) as u32;
-                    h3 = ((h3 as u64 + d as u64) & u32::MAX as u64) as u32;
-                    h4 = ((h4 as u64 + e as u64) & u32::MAX as u64) as u32;
-                    h5 = ((h5 as u64 + f as u64) & u32::MAX as u64) as u32;
-                    h6 = ((h6 as u64 + g as u64) & u32::MAX as u64) as u32;
-                    h7 = ((h7 as u64 + h as u64) & u32::MAX as u64) as u32;
-                }
+    fn block_size(&self) -> usize {
+        self.kind.block_size()
+    }
 
-                let mut hash = h0.to_be_bytes().to_vec();
-                hash.append(&mut h1.to_be_bytes().to_vec());
-                hash.append(&mut h2.to_be_bytes().to_vec());
-                hash.append(&mut h3.to_be_bytes().to_vec());
-                hash.append(&mut h4.to_be_bytes().to_vec());
-                hash.append(&mut h5.to_be_bytes().to_vec());
-                hash.append(&mut h6.to_be_bytes().to_vec());
-                hash.append(&mut h7.to_be_bytes().to_vec());
-
-                // Produce the final hash value (big-endian):
-                hash
+    fn output_size(&self) -> usize {
+        self.kind.output_size()
+    }
+}
+
+impl StreamingHashingAlgorithm for SHA {
+    fn update(&mut self, data: &[u8]) {
+        let block_size = self.kind.block_size();
+        self.length = self.length.wrapping_add(data.len() as u128);
+
+        let mut data = data;
+        if !self.buffer.is_empty() {
+            let needed = block_size - self.buffer.len();
+            let taken = needed.min(data.len());
+            self.buffer.extend_from_slice(&data[..taken]);
+            data = &data[taken..];
+
+            if self.buffer.len() < block_size {
+                return;
             }
+
+            self.state.compress(&self.buffer);
+            self.buffer.clear();
         }
+
+        // Process the message in successive 512-bit (1024-bit for SHA-512) chunks:
+        let mut chunks = data.chunks_exact(block_size);
+        for chunk in &mut chunks {
+            self.state.compress(chunk);
+        }
+
+        self.buffer.extend_from_slice(chunks.remainder());
+    }
+
+    fn finalize(&mut self) -> Vec<u8> {
+        let block_size = self.kind.block_size();
+        let length_size = block_size / 8;
+
+        // Pre-processing (Padding):
+        let num = self.length.wrapping_mul(8).to_be_bytes();
+
+        let mut padding = vec![128];
+        while !(self.buffer.len() + padding.len() + length_size).is_multiple_of(block_size) {
+            padding.push(0);
+        }
+        padding.extend_from_slice(&num[16 - length_size..]);
+
+        let length = self.length;
+        self.update(&padding);
+        self.length = length;
+
+        // Produce the final hash value (big-endian), truncated to the output size:
+        let mut hash = self.state.to_be_bytes();
+        hash.truncate(self.kind.output_size());
+
+        self.reset();
+        hash
+    }
+
+    fn reset(&mut self) {
+        self.state = self.kind.initial_state();
+        self.buffer.clear();
+        self.length = 0;
+    }
+}
+
+const SHA224_INIT: [u32; 8] = [
+    0xc1059ed8, 0x367cd507, 0x3070dd17, 0xf70e5939, 0xffc00b31, 0x68581511, 0x64f98fa7, 0xbefa4fa4,
+];
+
+const SHA256_INIT: [u32; 8] = [
+    0x6a09e667, 0xbb67ae85, 0x3c6ef372, 0xa54ff53a, 0x510e527f, 0x9b05688c, 0x1f83d9ab, 0x5be0cd19,
+];
+
+const SHA256_K: [u32; 64] = [
+    0x428a2f98, 0x71374491, 0xb5c0fbcf, 0xe9b5dba5, 0x3956c25b, 0x59f111f1, 0x923f82a4, 0xab1c5ed5,
+    0xd807aa98, 0x12835b01, 0x243185be, 0x550c7dc3, 0x72be5d74, 0x80deb1fe, 0x9bdc06a7, 0xc19bf174,
+    0xe49b69c1, 0xefbe4786, 0x0fc19dc6, 0x240ca1cc, 0x2de92c6f, 0x4a7484aa, 0x5cb0a9dc, 0x76f988da,
+    0x983e5152, 0xa831c66d, 0xb00327c8, 0xbf597fc7, 0xc6e00bf3, 0xd5a79147, 0x06ca6351, 0x14292967,
+    0x27b70a85, 0x2e1b2138, 0x4d2c6dfc, 0x53380d13, 0x650a7354, 0x766a0abb, 0x81c2c92e, 0x92722c85,
+    0xa2bfe8a1, 0xa81a664b, 0xc24b8b70, 0xc76c51a3, 0xd192e819, 0xd6990624, 0xf40e3585, 0x106aa070,
+    0x19a4c116, 0x1e376c08, 0x2748774c, 0x34b0bcb5, 0x391c0cb3, 0x4ed8aa4a, 0x5b9cca4f, 0x682e6ff3,
+    0x748f82ee, 0x78a5636f, 0x84c87814, 0x8cc70208, 0x90befffa, 0xa4506ceb, 0xbef9a3f7, 0xc67178f2,
+];
+
+fn sha256_compress(state: &mut [u32; 8], chunk: &[u8]) {
+    let mut w: [u32; 64] = [0; 64];
+    for (i, word) in chunk.chunks(4).enumerate() {
+        w[i] = ((word[0] as u32) << 24)
+            + ((word[1] as u32) << 16)
+            + ((word[2] as u32) << 8)
+            + (word[3] as u32);
+    }
+
+    // Extend the first 16 words into the remaining 48 words w[16..63] of the message schedule array:
+    for i in 16..64 {
+        let s0 = right_rotate(w[i - 15], 7) ^ right_rotate(w[i - 15], 18) ^ (w[i - 15] >> 3);
+        let s1 = right_rotate(w[i - 2], 17) ^ right_rotate(w[i - 2], 19) ^ (w[i - 2] >> 10);
+
+        w[i] = w[i - 16]
+            .wrapping_add(s0)
+            .wrapping_add(w[i - 7])
+            .wrapping_add(s1);
+    }
+
+    // Initialize working variables to current hash value:
+    let [mut a, mut b, mut c, mut d, mut e, mut f, mut g, mut h] = *state;
+
+    // Compression function main loop:
+    for i in 0..64 {
+        let s1 = right_rotate(e, 6) ^ right_rotate(e, 11) ^ right_rotate(e, 25);
+        let ch = (e & f) ^ ((!e) & g);
+        let temp1 = h
+            .wrapping_add(s1)
+            .wrapping_add(ch)
+            .wrapping_add(SHA256_K[i])
+            .wrapping_add(w[i]);
+        let s0 = right_rotate(a, 2) ^ right_rotate(a, 13) ^ right_rotate(a, 22);
+        let maj = (a & b) ^ (a & c) ^ (b & c);
+        let temp2 = s0.wrapping_add(maj);
+
+        h = g;
+        g = f;
+        f = e;
+        e = d.wrapping_add(temp1);
+        d = c;
+        c = b;
+        b = a;
+        a = temp1.wrapping_add(temp2);
+    }
+
+    // Add the compressed chunk to the current hash value:
+    for (h, v) in state.iter_mut().zip([a, b, c, d, e, f, g, h]) {
+        *h = h.wrapping_add(v);
+    }
+}
+
+const SHA384_INIT: [u64; 8] = [
+    0xcbbb9d5dc1059ed8, 0x629a292a367cd507, 0x9159015a3070dd17, 0x152fecd8f70e5939,
+    0x67332667ffc00b31, 0x8eb44a8768581511, 0xdb0c2e0d64f98fa7, 0x47b5481dbefa4fa4,
+];
+
+const SHA512_INIT: [u64; 8] = [
+    0x6a09e667f3bcc908, 0xbb67ae8584caa73b, 0x3c6ef372fe94f82b, 0xa54ff53a5f1d36f1,
+    0x510e527fade682d1, 0x9b05688c2b3e6c1f, 0x1f83d9abfb41bd6b, 0x5be0cd19137e2179,
+];
+
+const SHA512_224_INIT: [u64; 8] = [
+    0x8c3d37c819544da2, 0x73e1996689dcd4d6, 0x1dfab7ae32ff9c82, 0x679dd514582f9fcf,
+    0x0f6d2b697bd44da8, 0x77e36f7304c48942, 0x3f9d85a86a1d36c8, 0x1112e6ad91d692a1,
+];
+
+const SHA512_256_INIT: [u64; 8] = [
+    0x22312194fc2bf72c, 0x9f555fa3c84c64c2, 0x2393b86b6f53b151, 0x963877195940eabd,
+    0x96283ee2a88effe3, 0xbe5e1e2553863992, 0x2b0199fc2c85b8aa, 0x0eb72ddc81c52ca2,
+];
+
+const SHA512_K: [u64; 80] = [
+    0x428a2f98d728ae22, 0x7137449123ef65cd, 0xb5c0fbcfec4d3b2f, 0xe9b5dba58189dbbc,
+    0x3956c25bf348b538, 0x59f111f1b605d019, 0x923f82a4af194f9b, 0xab1c5ed5da6d8118,
+    0xd807aa98a3030242, 0x12835b0145706fbe, 0x243185be4ee4b28c, 0x550c7dc3d5ffb4e2,
+    0x72be5d74f27b896f, 0x80deb1fe3b1696b1, 0x9bdc06a725c71235, 0xc19bf174cf692694,
+    0xe49b69c19ef14ad2, 0xefbe4786384f25e3, 0x0fc19dc68b8cd5b5, 0x240ca1cc77ac9c65,
+    0x2de92c6f592b0275, 0x4a7484aa6ea6e483, 0x5cb0a9dcbd41fbd4, 0x76f988da831153b5,
+    0x983e5152ee66dfab, 0xa831c66d2db43210, 0xb00327c898fb213f, 0xbf597fc7beef0ee4,
+    0xc6e00bf33da88fc2, 0xd5a79147930aa725, 0x06ca6351e003826f, 0x142929670a0e6e70,
+    0x27b70a8546d22ffc, 0x2e1b21385c26c926, 0x4d2c6dfc5ac42aed, 0x53380d139d95b3df,
+    0x650a73548baf63de, 0x766a0abb3c77b2a8, 0x81c2c92e47edaee6, 0x92722c851482353b,
+    0xa2bfe8a14cf10364, 0xa81a664bbc423001, 0xc24b8b70d0f89791, 0xc76c51a30654be30,
+    0xd192e819d6ef5218, 0xd69906245565a910, 0xf40e35855771202a, 0x106aa07032bbd1b8,
+    0x19a4c116b8d2d0c8, 0x1e376c085141ab53, 0x2748774cdf8eeb99, 0x34b0bcb5e19b48a8,
+    0x391c0cb3c5c95a63, 0x4ed8aa4ae3418acb, 0x5b9cca4f7763e373, 0x682e6ff3d6b2b8a3,
+    0x748f82ee5defb2fc, 0x78a5636f43172f60, 0x84c87814a1f0ab72, 0x8cc702081a6439ec,
+    0x90befffa23631e28, 0xa4506cebde82bde9, 0xbef9a3f7b2c67915, 0xc67178f2e372532b,
+    0xca273eceea26619c, 0xd186b8c721c0c207, 0xeada7dd6cde0eb1e, 0xf57d4f7fee6ed178,
+    0x06f067aa72176fba, 0x0a637dc5a2c898a6, 0x113f9804bef90dae, 0x1b710b35131c471b,
+    0x28db77f523047d84, 0x32caab7b40c72493, 0x3c9ebe0a15c9bebc, 0x431d67c49c100d4c,
+    0x4cc5d4becb3e42b6, 0x597f299cfc657e2a, 0x5fcb6fab3ad6faec, 0x6c44198c4a475817,
+];
+
+fn sha512_compress(state: &mut [u64; 8], chunk: &[u8]) {
+    let mut w: [u64; 80] = [0; 80];
+    for (i, word) in chunk.chunks(8).enumerate() {
+        let mut num = 0;
+        for byte in word {
+            num = (num << 8) + *byte as u64;
+        }
+        w[i] = num;
+    }
+
+    // Extend the first 16 words into the remaining 64 words w[16..79] of the message schedule array:
+    for i in 16..80 {
+        let s0 = w[i - 15].rotate_right(1) ^ w[i - 15].rotate_right(8) ^ (w[i - 15] >> 7);
+        let s1 = w[i - 2].rotate_right(19) ^ w[i - 2].rotate_right(61) ^ (w[i - 2] >> 6);
+
+        w[i] = w[i - 16]
+            .wrapping_add(s0)
+            .wrapping_add(w[i - 7])
+            .wrapping_add(s1);
+    }
+
+    // Initialize working variables to current hash value:
+    let [mut a, mut b, mut c, mut d, mut e, mut f, mut g, mut h] = *state;
+
+    // Compression function main loop:
+    for i in 0..80 {
+        let s1 = e.rotate_right(14) ^ e.rotate_right(18) ^ e.rotate_right(41);
+        let ch = (e & f) ^ ((!e) & g);
+        let temp1 = h
+            .wrapping_add(s1)
+            .wrapping_add(ch)
+            .wrapping_add(SHA512_K[i])
+            .wrapping_add(w[i]);
+        let s0 = a.rotate_right(28) ^ a.rotate_right(34) ^ a.rotate_right(39);
+        let maj = (a & b) ^ (a & c) ^ (b & c);
+        let temp2 = s0.wrapping_add(maj);
+
+        h = g;
+        g = f;
+        f = e;
+        e = d.wrapping_add(temp1);
+        d = c;
+        c = b;
+        b = a;
+        a = temp1.wrapping_add(temp2);
+    }
+
+    // Add the compressed chunk to the current hash value:
+    for (h, v) in state.iter_mut().zip([a, b, c, d, e, f, g, h]) {
+        *h = h.wrapping_add(v);
     }
 }
@@ -0,0 +1,312 @@
+use crate::cryptography::{HashingAlgorithm, StreamingHashingAlgorithm};
+
+#[derive(Clone, Copy)]
+pub enum SHA3Type {
+    SHA3_224,
+    SHA3_256,
+    SHA3_384,
+    SHA3_512,
+}
+
+impl SHA3Type {
+    fn output_size(&self) -> usize {
+        match self {
+            SHA3Type::SHA3_224 => 28,
+            SHA3Type::SHA3_256 => 32,
+            SHA3Type::SHA3_384 => 48,
+            SHA3Type::SHA3_512 => 64,
+        }
+    }
+
+    fn rate(&self) -> usize {
+        200 - 2 * self.output_size()
+    }
+}
+
+#[derive(Clone, Copy)]
+pub enum SHAKEType {
+    SHAKE128,
+    SHAKE256,
+}
+
+impl SHAKEType {
+    fn rate(&self) -> usize {
+        match self {
+            SHAKEType::SHAKE128 => 168,
+            SHAKEType::SHAKE256 => 136,
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct SHA3 {
+    kind: SHA3Type,
+    sponge: Sponge,
+}
+
+impl Default for SHA3 {
+    fn default() -> Self {
+        SHA3::new()
+    }
+}
+
+impl SHA3 {
+    pub fn new() -> SHA3 {
+        SHA3::from_type(SHA3Type::SHA3_256)
+    }
+
+    pub fn from_type(kind: SHA3Type) -> SHA3 {
+        SHA3 {
+            kind,
+            sponge: Sponge::new(kind.rate(), SHA3_DELIMITER),
+        }
+    }
+
+    pub fn kind(&self) -> SHA3Type {
+        self.kind
+    }
+}
+
+impl HashingAlgorithm for SHA3 {
+    fn hash(&self, message: Vec<u8>) -> Vec<u8> {
+        let mut sha3 = SHA3::from_type(self.kind);
+        sha3.update(&message);
+        sha3.finalize()
+    }
+
+    fn block_size(&self) -> usize {
+        self.kind.rate()
+    }
+
+    fn output_size(&self) -> usize {
+        self.kind.output_size()
+    }
+}
+
+impl StreamingHashingAlgorithm for SHA3 {
+    fn update(&mut self, data: &[u8]) {
+        self.sponge.absorb(data);
+    }
+
+    fn finalize(&mut self) -> Vec<u8> {
+        let hash = self.sponge.squeeze(self.kind.output_size());
+        self.reset();
+        hash
+    }
+
+    fn reset(&mut self) {
+        self.sponge = Sponge::new(self.kind.rate(), SHA3_DELIMITER);
+    }
+}
+
+// Extendable-output function; `finalize` and `hash` produce `output_size` bytes.
+// `finalize_xof` consumes the hasher into a reader for an arbitrarily long output
+// stream, so absorbing after squeezing has started cannot be expressed
+#[derive(Clone)]
+pub struct SHAKE {
+    kind: SHAKEType,
+    output_size: usize,
+    sponge: Sponge,
+}
+
+impl Default for SHAKE {
+    fn default() -> Self {
+        SHAKE::new()
+    }
+}
+
+impl SHAKE {
+    pub fn new() -> SHAKE {
+        SHAKE::from_type(SHAKEType::SHAKE128, 32)
+    }
+
+    pub fn from_type(kind: SHAKEType, output_size: usize) -> SHAKE {
+        SHAKE {
+            kind,
+            output_size,
+            sponge: Sponge::new(kind.rate(), SHAKE_DELIMITER),
+        }
+    }
+
+    pub fn kind(&self) -> SHAKEType {
+        self.kind
+    }
+
+    pub fn finalize_xof(self) -> SHAKEReader {
+        SHAKEReader {
+            sponge: self.sponge,
+        }
+    }
+}
+
+impl HashingAlgorithm for SHAKE {
+    fn hash(&self, message: Vec<u8>) -> Vec<u8> {
+        let mut shake = SHAKE::from_type(self.kind, self.output_size);
+        shake.update(&message);
+        shake.finalize()
+    }
+
+    fn block_size(&self) -> usize {
+        self.kind.rate()
+    }
+
+    fn output_size(&self) -> usize {
+        self.output_size
+    }
+}
+
+impl StreamingHashingAlgorithm for SHAKE {
+    fn update(&mut self, data: &[u8]) {
+        self.sponge.absorb(data);
+    }
+
+    fn finalize(&mut self) -> Vec<u8> {
+        let hash = self.sponge.squeeze(self.output_size);
+        self.reset();
+        hash
+    }
+
+    fn reset(&mut self) {
+        self.sponge = Sponge::new(self.kind.rate(), SHAKE_DELIMITER);
+    }
+}
+
+// Output stream of a finalized SHAKE, each call continues where the last one ended
+#[derive(Clone)]
+pub struct SHAKEReader {
+    sponge: Sponge,
+}
+
+impl SHAKEReader {
+    pub fn squeeze(&mut self, length: usize) -> Vec<u8> {
+        self.sponge.squeeze(length)
+    }
+}
+
+const SHA3_DELIMITER: u8 = 0x06;
+const SHAKE_DELIMITER: u8 = 0x1f;
+
+#[derive(Clone)]
+struct Sponge {
+    state: [u64; 25],
+    rate: usize,
+    delimiter: u8,
+    position: usize,
+    squeezing: bool,
+}
+
+impl Sponge {
+    fn new(rate: usize, delimiter: u8) -> Self {
+        Sponge {
+            state: [0; 25],
+            rate,
+            delimiter,
+            position: 0,
+            squeezing: false,
+        }
+    }
+
+    fn xor_byte(&mut self, index: usize, byte: u8) {
+        self.state[index / 8] ^= (byte as u64) << (8 * (index % 8));
+    }
+
+    fn byte(&self, index: usize) -> u8 {
+        (self.state[index / 8] >> (8 * (index % 8))) as u8
+    }
+
+    fn absorb(&mut self, data: &[u8]) {
+        assert!(!self.squeezing, "cannot absorb after squeezing has started");
+
+        for byte in data {
+            self.xor_byte(self.position, *byte);
+            self.position += 1;
+
+            if self.position == self.rate {
+                keccak_f(&mut self.state);
+                self.position = 0;
+            }
+        }
+    }
+
+    fn squeeze(&mut self, length: usize) -> Vec<u8> {
+        if !self.squeezing {
+            // Pad with the domain separation bits followed by pad10*1
+            self.xor_byte(self.position, self.delimiter);
+            self.xor_byte(self.rate - 1, 0x80);
+            keccak_f(&mut self.state);
+            self.position = 0;
+            self.squeezing = true;
+        }
+
+        let mut output = Vec::with_capacity(length);
+        while output.len() < length {
+            if self.position == self.rate {
+                keccak_f(&mut self.state);
+                self.position = 0;
+            }
+
+            output.push(self.byte(self.position));
+            self.position += 1;
+        }
+
+        output
+    }
+}
+
+const ROUND_CONSTANTS: [u64; 24] = [
+    0x0000000000000001, 0x0000000000008082, 0x800000000000808a, 0x8000000080008000,
+    0x000000000000808b, 0x0000000080000001, 0x8000000080008081, 0x8000000000008009,
+    0x000000000000008a, 0x0000000000000088, 0x0000000080008009, 0x000000008000000a,
+    0x000000008000808b, 0x800000000000008b, 0x8000000000008089, 0x8000000000008003,
+    0x8000000000008002, 0x8000000000000080, 0x000000000000800a, 0x800000008000000a,
+    0x8000000080008081, 0x8000000000008080, 0x0000000080000001, 0x8000000080008008,
+];
+
+const ROTATIONS: [u32; 24] = [
+    1, 3, 6, 10, 15, 21, 28, 36, 45, 55, 2, 14, 27, 41, 56, 8, 25, 43, 62, 18, 39, 61, 20, 44,
+];
+
+const PI_LANES: [usize; 24] = [
+    10, 7, 11, 17, 18, 3, 5, 16, 8, 21, 24, 4, 15, 23, 19, 13, 12, 2, 20, 14, 22, 9, 6, 1,
+];
+
+fn keccak_f(state: &mut [u64; 25]) {
+    for round_constant in ROUND_CONSTANTS {
+        // Theta
+        let mut c = [0u64; 5];
+        for (x, column) in c.iter_mut().enumerate() {
+            *column = state[x] ^ state[x + 5] ^ state[x + 10] ^ state[x + 15] ^ state[x + 20];
+        }
+        for x in 0..5 {
+            let d = c[(x + 4) % 5] ^ c[(x + 1) % 5].rotate_left(1);
+            for y in 0..5 {
+                state[x + 5 * y] ^= d;
+            }
+        }
+
+        // Rho and pi
+        let mut current = state[1];
+        for (rotation, lane) in ROTATIONS.iter().zip(PI_LANES) {
+            let temp = state[lane];
+            state[lane] = current.rotate_left(*rotation);
+            current = temp;
+        }
+
+        // Chi
+        for y in 0..5 {
+            let row = [
+                state[5 * y],
+                state[5 * y + 1],
+                state[5 * y + 2],
+                state[5 * y + 3],
+                state[5 * y + 4],
+            ];
+            for x in 0..5 {
+                state[5 * y + x] = row[x] ^ ((!row[(x + 1) % 5]) & row[(x + 2) % 5]);
+            }
+        }
+
+        // Iota
+        state[0] ^= round_constant;
+    }
+}
@@ -1,3 +1,11 @@
+mod biguint;
+mod montgomery;
+mod prime;
+
+pub use biguint::BigUint;
+pub use montgomery::Montgomery;
+pub use prime::{generate_prime, is_probable_prime};
+
 pub fn finite_field_multiplication(n1: u8, n2: u8) -> u8 {
     let mut a: u8 = n1;
     let mut b: u8 = n2;
@@ -22,6 +30,55 @@ pub fn finite_field_multiplication(n1: u8, n2: u8) -> u8 {
     p
 }
 
+// Multiplication in GF(2^128) with the bit ordering and reduction polynomial used by GCM
+pub fn gf128_multiplication(x: u128, y: u128) -> u128 {
+    let r: u128 = 0xe1 << 120;
+    let mut z: u128 = 0;
+    let mut v = y;
+
+    for i in (0..128).rev() {
+        let bit = (x >> i) & 1;
+        z ^= v & bit.wrapping_neg();
+
+        let carry = v & 1;
+        v >>= 1;
+        v ^= r & carry.wrapping_neg();
+    }
+
+    z
+}
+
+pub fn ghash(h: u128, aad: &[u8], cipher: &[u8]) -> u128 {
+    let mut y: u128 = 0;
+
+    for data in [aad, cipher] {
+        for chunk in data.chunks(16) {
+            let mut block = [0; 16];
+            block[..chunk.len()].copy_from_slice(chunk);
+            y = gf128_multiplication(y ^ u128::from_be_bytes(block), h);
+        }
+    }
+
+    let lengths = ((aad.len() as u128 * 8) << 64) | (cipher.len() as u128 * 8);
+    gf128_multiplication(y ^ lengths, h)
+}
+
+// POLYVAL from RFC 8452, computed through GHASH on byte-reversed blocks
+pub fn polyval(h: &[u8; 16], data: &[u8]) -> [u8; 16] {
+    let h = u128::from_le_bytes(*h);
+    let carry = h & 1;
+    let h = (h >> 1) ^ ((0xe1 << 120) & carry.wrapping_neg());
+
+    let mut y: u128 = 0;
+    for chunk in data.chunks(16) {
+        let mut block = [0; 16];
+        block[..chunk.len()].copy_from_slice(chunk);
+        y = gf128_multiplication(y ^ u128::from_le_bytes(block), h);
+    }
+
+    y.to_le_bytes()
+}
+
 pub fn gcd(e: i128, t: i128, x: &mut i128, y: &mut i128) -> i128 {
     if e == 0 {
         *x = 0;
@@ -36,27 +93,49 @@ pub fn gcd(e: i128, t: i128, x: &mut i128, y: &mut i128) -> i128 {
     gcd
 }
 
-pub fn exponential_modulus(m: u128, e: u128, n: u128) -> u128 {
-    let mut c = 1;
-    let mut f = 0;
-    while f < e {
-        f += 1;
-        c = (m * c) % n;
+// a + b mod n for a, b < n without overflowing when n is above 2^127
+fn add_modulus(a: u128, b: u128, n: u128) -> u128 {
+    if a >= n - b {
+        a - (n - b)
+    } else {
+        a + b
     }
-    c
 }
 
-pub fn right_rotate(mut bytes: u32, amount: u8) -> u32 {
-    let base: u32 = 2;
-    let mut num = (amount > 0) as u32;
-    for i in 1..=amount {
-        num += base.pow(i as u32);
+// Double and add so the product never needs more than 128 bits
+pub fn multiply_modulus(a: u128, b: u128, n: u128) -> u128 {
+    let mut a = a % n;
+    let mut b = b % n;
+    let mut product = 0;
+
+    while b != 0 {
+        if b & 1 == 1 {
+            product = add_modulus(product, a, n);
+        }
+        a = add_modulus(a, a, n);
+        b >>= 1;
+    }
+
+    product
+}
+
+// Right to left square and multiply
+pub fn exponential_modulus(m: u128, e: u128, n: u128) -> u128 {
+    let mut base = m % n;
+    let mut e = e;
+    let mut c = 1 % n;
+
+    while e != 0 {
+        if e & 1 == 1 {
+            c = multiply_modulus(c, base, n);
+        }
+        base = multiply_modulus(base, base, n);
+        e >>= 1;
     }
 
-    let mut part = bytes & num;
-    bytes >>= amount;
-    part <<= 32 - amount;
-    bytes += part;
+    c
+}
 
-    bytes
+pub fn right_rotate(bytes: u32, amount: u8) -> u32 {
+    bytes.rotate_right(amount as u32)
 }
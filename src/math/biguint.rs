@@ -0,0 +1,452 @@
+use crate::math::Montgomery;
+
+use std::cmp::Ordering;
+use std::ops::{Add, Div, Mul, Rem, Shl, Shr, Sub};
+
+// Unsigned integer of any size, stored as little-endian 64-bit limbs without
+// leading zero limbs, so zero has no limbs at all
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct BigUint {
+    limbs: Vec<u64>,
+}
+
+impl Default for BigUint {
+    fn default() -> Self {
+        BigUint::zero()
+    }
+}
+
+impl BigUint {
+    pub fn zero() -> Self {
+        BigUint { limbs: Vec::new() }
+    }
+
+    pub fn one() -> Self {
+        BigUint::from_u128(1)
+    }
+
+    pub fn from_u128(value: u128) -> Self {
+        BigUint::from_limbs(vec![value as u64, (value >> 64) as u64])
+    }
+
+    pub fn from_be_bytes(bytes: &[u8]) -> Self {
+        let limbs = bytes
+            .rchunks(8)
+            .map(|chunk| {
+                let mut limb = [0; 8];
+                limb[8 - chunk.len()..].copy_from_slice(chunk);
+                u64::from_be_bytes(limb)
+            })
+            .collect();
+
+        BigUint::from_limbs(limbs)
+    }
+
+    pub(super) fn from_limbs(limbs: Vec<u64>) -> Self {
+        let mut number = BigUint { limbs };
+        number.normalize();
+        number
+    }
+
+    pub(super) fn limbs(&self) -> &[u64] {
+        &self.limbs
+    }
+
+    fn normalize(&mut self) {
+        while self.limbs.last() == Some(&0) {
+            self.limbs.pop();
+        }
+    }
+
+    // Shortest big-endian encoding, zero is a single zero byte
+    pub fn to_be_bytes(&self) -> Vec<u8> {
+        let length = self.bits().div_ceil(8).max(1);
+        self.to_be_bytes_padded(length).unwrap()
+    }
+
+    // Big-endian encoding left padded with zeros to `length` bytes, None if it does not fit
+    pub fn to_be_bytes_padded(&self, length: usize) -> Option<Vec<u8>> {
+        if self.bits() > length * 8 {
+            return None;
+        }
+
+        let mut bytes = vec![0; length];
+        for (i, byte) in bytes.iter_mut().rev().enumerate() {
+            if let Some(limb) = self.limbs.get(i / 8) {
+                *byte = (limb >> (8 * (i % 8))) as u8;
+            }
+        }
+
+        Some(bytes)
+    }
+
+    pub fn to_u128(&self) -> Option<u128> {
+        match self.limbs.len() {
+            0 => Some(0),
+            1 => Some(self.limbs[0] as u128),
+            2 => Some(((self.limbs[1] as u128) << 64) | self.limbs[0] as u128),
+            _ => None,
+        }
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.limbs.is_empty()
+    }
+
+    pub fn is_odd(&self) -> bool {
+        self.limbs.first().is_some_and(|limb| limb & 1 == 1)
+    }
+
+    pub fn bits(&self) -> usize {
+        match self.limbs.last() {
+            Some(limb) => 64 * self.limbs.len() - limb.leading_zeros() as usize,
+            None => 0,
+        }
+    }
+
+    pub fn bit(&self, index: usize) -> bool {
+        self.limbs
+            .get(index / 64)
+            .is_some_and(|limb| (limb >> (index % 64)) & 1 == 1)
+    }
+
+    pub fn checked_sub(&self, other: &BigUint) -> Option<BigUint> {
+        if self < other {
+            return None;
+        }
+
+        let mut limbs = self.limbs.clone();
+        let mut borrow = false;
+        for (i, limb) in limbs.iter_mut().enumerate() {
+            let (difference, b1) = limb.overflowing_sub(*other.limbs.get(i).unwrap_or(&0));
+            let (difference, b2) = difference.overflowing_sub(borrow as u64);
+            *limb = difference;
+            borrow = b1 || b2;
+        }
+
+        Some(BigUint::from_limbs(limbs))
+    }
+
+    // Square and multiply in Montgomery form for odd moduli, plain reduction otherwise
+    pub fn modpow(&self, exponent: &BigUint, modulus: &BigUint) -> BigUint {
+        assert!(!modulus.is_zero(), "modulus is zero");
+
+        if let Some(montgomery) = Montgomery::new(modulus) {
+            return montgomery.pow(self, exponent);
+        }
+
+        let base = self % modulus;
+        let mut result = &BigUint::one() % modulus;
+        for i in (0..exponent.bits()).rev() {
+            result = &(&result * &result) % modulus;
+            if exponent.bit(i) {
+                result = &(&result * &base) % modulus;
+            }
+        }
+
+        result
+    }
+
+    // For secret exponents, see `Montgomery::pow_constant_time`
+    pub fn modpow_constant_time(&self, exponent: &BigUint, modulus: &BigUint) -> BigUint {
+        Montgomery::new(modulus)
+            .expect("constant-time exponentiation needs an odd modulus")
+            .pow_constant_time(self, exponent)
+    }
+
+    pub fn gcd(&self, other: &BigUint) -> BigUint {
+        let (mut a, mut b) = (self.clone(), other.clone());
+        while !b.is_zero() {
+            let remainder = &a % &b;
+            a = b;
+            b = remainder;
+        }
+        a
+    }
+
+    // Extended Euclid with the coefficients kept modulo `modulus`, None if not coprime
+    pub fn mod_inverse(&self, modulus: &BigUint) -> Option<BigUint> {
+        let (mut r0, mut r1) = (modulus.clone(), self % modulus);
+        let (mut s0, mut s1) = (BigUint::zero(), BigUint::one());
+
+        while !r1.is_zero() {
+            let (quotient, remainder) = r0.div_rem(&r1);
+            let s2 = &(&(&s0 + modulus) - &(&(&quotient * &s1) % modulus)) % modulus;
+
+            (r0, r1) = (r1, remainder);
+            (s0, s1) = (s1, s2);
+        }
+
+        match r0 == BigUint::one() {
+            true => Some(&s0 % modulus),
+            false => None,
+        }
+    }
+
+    pub fn div_rem(&self, divisor: &BigUint) -> (BigUint, BigUint) {
+        assert!(!divisor.is_zero(), "division by zero");
+
+        if self < divisor {
+            return (BigUint::zero(), self.clone());
+        }
+
+        if divisor.limbs.len() == 1 {
+            let (quotient, remainder) = self.div_rem_limb(divisor.limbs[0]);
+            return (quotient, BigUint::from_u128(remainder as u128));
+        }
+
+        self.div_rem_knuth(divisor)
+    }
+
+    fn div_rem_limb(&self, divisor: u64) -> (BigUint, u64) {
+        let mut quotient = vec![0; self.limbs.len()];
+        let mut remainder: u128 = 0;
+
+        for (q, limb) in quotient.iter_mut().zip(&self.limbs).rev() {
+            let current = (remainder << 64) | *limb as u128;
+            *q = (current / divisor as u128) as u64;
+            remainder = current % divisor as u128;
+        }
+
+        (BigUint::from_limbs(quotient), remainder as u64)
+    }
+
+    // Knuth, The Art of Computer Programming volume 2, algorithm 4.3.1 D
+    fn div_rem_knuth(&self, divisor: &BigUint) -> (BigUint, BigUint) {
+        // Normalise so the top limb of the divisor has its high bit set
+        let shift = divisor.limbs.last().unwrap().leading_zeros() as usize;
+        let v = (divisor << shift).limbs;
+        let mut u = (self << shift).limbs;
+        u.resize(self.limbs.len() + 1, 0);
+
+        let n = v.len();
+        let m = u.len() - n;
+        let mut quotient = vec![0; m];
+
+        for j in (0..m).rev() {
+            let numerator = ((u[j + n] as u128) << 64) | u[j + n - 1] as u128;
+            let mut q_hat = numerator / v[n - 1] as u128;
+            let mut r_hat = numerator % v[n - 1] as u128;
+
+            while q_hat >> 64 != 0
+                || q_hat * v[n - 2] as u128 > ((r_hat << 64) | u[j + n - 2] as u128)
+            {
+                q_hat -= 1;
+                r_hat += v[n - 1] as u128;
+                if r_hat >> 64 != 0 {
+                    break;
+                }
+            }
+
+            // Multiply and subtract q_hat * v from the current window of u
+            let mut carry: u128 = 0;
+            let mut borrow = false;
+            for i in 0..n {
+                let product = q_hat * v[i] as u128 + carry;
+                carry = product >> 64;
+
+                let (difference, b1) = u[i + j].overflowing_sub(product as u64);
+                let (difference, b2) = difference.overflowing_sub(borrow as u64);
+                u[i + j] = difference;
+                borrow = b1 || b2;
+            }
+            let (difference, b1) = u[j + n].overflowing_sub(carry as u64);
+            let (difference, b2) = difference.overflowing_sub(borrow as u64);
+            u[j + n] = difference;
+
+            // q_hat was one too large, add the divisor back
+            if b1 || b2 {
+                q_hat -= 1;
+
+                let mut carry: u128 = 0;
+                for i in 0..n {
+                    let sum = u[i + j] as u128 + v[i] as u128 + carry;
+                    u[i + j] = sum as u64;
+                    carry = sum >> 64;
+                }
+                u[j + n] = u[j + n].wrapping_add(carry as u64);
+            }
+
+            quotient[j] = q_hat as u64;
+        }
+
+        u.truncate(n);
+        let remainder = BigUint::from_limbs(u) >> shift;
+
+        (BigUint::from_limbs(quotient), remainder)
+    }
+}
+
+impl Ord for BigUint {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.limbs
+            .len()
+            .cmp(&other.limbs.len())
+            .then_with(|| self.limbs.iter().rev().cmp(other.limbs.iter().rev()))
+    }
+}
+
+impl PartialOrd for BigUint {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Add for &BigUint {
+    type Output = BigUint;
+
+    fn add(self, other: &BigUint) -> BigUint {
+        let (longer, shorter) = if self.limbs.len() >= other.limbs.len() {
+            (self, other)
+        } else {
+            (other, self)
+        };
+
+        let mut limbs = Vec::with_capacity(longer.limbs.len() + 1);
+        let mut carry = false;
+        for (i, limb) in longer.limbs.iter().enumerate() {
+            let (sum, c1) = limb.overflowing_add(*shorter.limbs.get(i).unwrap_or(&0));
+            let (sum, c2) = sum.overflowing_add(carry as u64);
+            limbs.push(sum);
+            carry = c1 || c2;
+        }
+        limbs.push(carry as u64);
+
+        BigUint::from_limbs(limbs)
+    }
+}
+
+// Panics if the result would be negative, see `checked_sub`
+impl Sub for &BigUint {
+    type Output = BigUint;
+
+    fn sub(self, other: &BigUint) -> BigUint {
+        self.checked_sub(other).expect("subtraction underflow")
+    }
+}
+
+impl Mul for &BigUint {
+    type Output = BigUint;
+
+    fn mul(self, other: &BigUint) -> BigUint {
+        let mut limbs = vec![0; self.limbs.len() + other.limbs.len()];
+
+        for (i, a) in self.limbs.iter().enumerate() {
+            let mut carry: u128 = 0;
+            for (j, b) in other.limbs.iter().enumerate() {
+                let product = *a as u128 * *b as u128 + limbs[i + j] as u128 + carry;
+                limbs[i + j] = product as u64;
+                carry = product >> 64;
+            }
+            limbs[i + other.limbs.len()] = carry as u64;
+        }
+
+        BigUint::from_limbs(limbs)
+    }
+}
+
+impl Div for &BigUint {
+    type Output = BigUint;
+
+    fn div(self, other: &BigUint) -> BigUint {
+        self.div_rem(other).0
+    }
+}
+
+impl Rem for &BigUint {
+    type Output = BigUint;
+
+    fn rem(self, other: &BigUint) -> BigUint {
+        self.div_rem(other).1
+    }
+}
+
+impl Shl<usize> for &BigUint {
+    type Output = BigUint;
+
+    fn shl(self, amount: usize) -> BigUint {
+        if self.is_zero() {
+            return BigUint::zero();
+        }
+
+        let (limb_shift, bit_shift) = (amount / 64, amount % 64);
+        let mut limbs = vec![0; limb_shift];
+        limbs.reserve(self.limbs.len() + 1);
+
+        let mut carry = 0;
+        for limb in &self.limbs {
+            limbs.push((limb << bit_shift) | carry);
+            carry = match bit_shift {
+                0 => 0,
+                _ => limb >> (64 - bit_shift),
+            };
+        }
+        limbs.push(carry);
+
+        BigUint::from_limbs(limbs)
+    }
+}
+
+impl Shr<usize> for &BigUint {
+    type Output = BigUint;
+
+    fn shr(self, amount: usize) -> BigUint {
+        let (limb_shift, bit_shift) = (amount / 64, amount % 64);
+        if limb_shift >= self.limbs.len() {
+            return BigUint::zero();
+        }
+
+        let limbs = &self.limbs[limb_shift..];
+        let shifted = limbs
+            .iter()
+            .enumerate()
+            .map(|(i, limb)| match bit_shift {
+                0 => *limb,
+                _ => (limb >> bit_shift) | (limbs.get(i + 1).unwrap_or(&0) << (64 - bit_shift)),
+            })
+            .collect();
+
+        BigUint::from_limbs(shifted)
+    }
+}
+
+// Owned versions forward to the reference implementations
+macro_rules! forward_owned {
+    ($($trait:ident $method:ident),*) => {
+        $(
+            impl $trait for BigUint {
+                type Output = BigUint;
+
+                fn $method(self, other: BigUint) -> BigUint {
+                    (&self).$method(&other)
+                }
+            }
+
+            impl $trait<&BigUint> for BigUint {
+                type Output = BigUint;
+
+                fn $method(self, other: &BigUint) -> BigUint {
+                    (&self).$method(other)
+                }
+            }
+        )*
+    };
+}
+
+forward_owned!(Add add, Sub sub, Mul mul, Div div, Rem rem);
+
+impl Shl<usize> for BigUint {
+    type Output = BigUint;
+
+    fn shl(self, amount: usize) -> BigUint {
+        &self << amount
+    }
+}
+
+impl Shr<usize> for BigUint {
+    type Output = BigUint;
+
+    fn shr(self, amount: usize) -> BigUint {
+        &self >> amount
+    }
+}
@@ -0,0 +1,158 @@
+use crate::math::BigUint;
+
+// Arithmetic modulo an odd number in Montgomery form, x is stored as x * R mod n
+// with R = 2^(64 * limbs). Values are fixed-width limb vectors so the
+// multiplication does the same work whatever the operands are.
+pub struct Montgomery {
+    modulus: Vec<u64>,
+    big_modulus: BigUint,
+    inverse: u64,
+    r_squared: Vec<u64>,
+}
+
+impl Montgomery {
+    // None unless the modulus is odd and larger than one
+    pub fn new(modulus: &BigUint) -> Option<Self> {
+        if !modulus.is_odd() || *modulus == BigUint::one() {
+            return None;
+        }
+
+        let length = modulus.bits().div_ceil(64);
+        let limbs = to_limbs(modulus, length);
+
+        // Newton iteration for n^-1 mod 2^64, each step doubles the correct bits
+        let mut inverse: u64 = 1;
+        for _ in 0..6 {
+            inverse = inverse.wrapping_mul(2u64.wrapping_sub(limbs[0].wrapping_mul(inverse)));
+        }
+
+        let r_squared = &(&BigUint::one() << (128 * length)) % modulus;
+
+        Some(Montgomery {
+            modulus: limbs,
+            big_modulus: modulus.clone(),
+            inverse: inverse.wrapping_neg(),
+            r_squared: to_limbs(&r_squared, length),
+        })
+    }
+
+    pub fn modulus(&self) -> &BigUint {
+        &self.big_modulus
+    }
+
+    // Coarsely integrated operand scanning, returns a * b / R mod n
+    fn multiply(&self, a: &[u64], b: &[u64]) -> Vec<u64> {
+        let n = self.modulus.len();
+        let mut t = vec![0u64; n + 2];
+
+        for &a_i in a {
+            let mut carry: u128 = 0;
+            for j in 0..n {
+                let sum = t[j] as u128 + a_i as u128 * b[j] as u128 + carry;
+                t[j] = sum as u64;
+                carry = sum >> 64;
+            }
+            let sum = t[n] as u128 + carry;
+            t[n] = sum as u64;
+            t[n + 1] = (sum >> 64) as u64;
+
+            // Add a multiple of n that clears the lowest limb, then drop it
+            let m = t[0].wrapping_mul(self.inverse);
+            let sum = t[0] as u128 + m as u128 * self.modulus[0] as u128;
+            let mut carry = sum >> 64;
+            for j in 1..n {
+                let sum = t[j] as u128 + m as u128 * self.modulus[j] as u128 + carry;
+                t[j - 1] = sum as u64;
+                carry = sum >> 64;
+            }
+            let sum = t[n] as u128 + carry;
+            t[n - 1] = sum as u64;
+            t[n] = t[n + 1] + (sum >> 64) as u64;
+            t[n + 1] = 0;
+        }
+
+        // t < 2n, subtract n and keep whichever is in range without branching
+        let mut reduced = vec![0; n];
+        let mut borrow = false;
+        for j in 0..n {
+            let (difference, b1) = t[j].overflowing_sub(self.modulus[j]);
+            let (difference, b2) = difference.overflowing_sub(borrow as u64);
+            reduced[j] = difference;
+            borrow = b1 || b2;
+        }
+        let (_, underflow) = t[n].overflowing_sub(borrow as u64);
+
+        let keep = (underflow as u64).wrapping_neg();
+        for (r, t) in reduced.iter_mut().zip(t) {
+            *r = (t & keep) | (*r & !keep);
+        }
+
+        reduced
+    }
+
+    fn encode(&self, x: &BigUint) -> Vec<u64> {
+        let x = to_limbs(&(x % &self.big_modulus), self.modulus.len());
+        self.multiply(&x, &self.r_squared)
+    }
+
+    fn decode(&self, x: &[u64]) -> BigUint {
+        let mut one = vec![0; self.modulus.len()];
+        one[0] = 1;
+        BigUint::from_limbs(self.multiply(x, &one))
+    }
+
+    // x^2 mod n with the same work for every x below n
+    pub(super) fn square(&self, x: &BigUint) -> BigUint {
+        let x = self.encode(x);
+        self.decode(&self.multiply(&x, &x))
+    }
+
+    // Left to right square and multiply, the running time depends on the exponent
+    pub fn pow(&self, base: &BigUint, exponent: &BigUint) -> BigUint {
+        let base = self.encode(base);
+        let mut result = self.encode(&BigUint::one());
+
+        for i in (0..exponent.bits()).rev() {
+            result = self.multiply(&result, &result);
+            if exponent.bit(i) {
+                result = self.multiply(&result, &base);
+            }
+        }
+
+        self.decode(&result)
+    }
+
+    // Montgomery ladder for secret exponents: every bit up to the modulus size costs
+    // one multiplication and one squaring, and the bit only drives a masked swap
+    pub fn pow_constant_time(&self, base: &BigUint, exponent: &BigUint) -> BigUint {
+        let mut r0 = self.encode(&BigUint::one());
+        let mut r1 = self.encode(base);
+
+        let bits = exponent.bits().max(self.big_modulus.bits());
+        for i in (0..bits).rev() {
+            let bit = exponent.bit(i);
+
+            conditional_swap(&mut r0, &mut r1, bit);
+            r1 = self.multiply(&r0, &r1);
+            r0 = self.multiply(&r0, &r0);
+            conditional_swap(&mut r0, &mut r1, bit);
+        }
+
+        self.decode(&r0)
+    }
+}
+
+fn conditional_swap(a: &mut [u64], b: &mut [u64], swap: bool) {
+    let mask = (swap as u64).wrapping_neg();
+    for (x, y) in a.iter_mut().zip(b.iter_mut()) {
+        let difference = (*x ^ *y) & mask;
+        *x ^= difference;
+        *y ^= difference;
+    }
+}
+
+fn to_limbs(x: &BigUint, length: usize) -> Vec<u64> {
+    let mut limbs = x.limbs().to_vec();
+    limbs.resize(length, 0);
+    limbs
+}
@@ -0,0 +1,135 @@
+extern crate rand;
+use rand::Rng;
+
+use crate::math::{BigUint, Montgomery};
+
+// Miller-Rabin rounds for generated primes, a composite passes one round with
+// probability at most 1/4 and far less for random candidates
+const ROUNDS: usize = 40;
+
+// Primes below 2000 for trial division, sieved once at compile time
+const SMALL_PRIMES: [u64; 303] = small_primes();
+
+const fn small_primes() -> [u64; 303] {
+    let limit = 2000;
+    let mut composite = [false; 2000];
+    let mut primes = [0; 303];
+    let mut count = 0;
+
+    let mut i = 2;
+    while i < limit {
+        if !composite[i] {
+            primes[count] = i as u64;
+            count += 1;
+
+            let mut multiple = i * i;
+            while multiple < limit {
+                composite[multiple] = true;
+                multiple += i;
+            }
+        }
+        i += 1;
+    }
+
+    assert!(count == primes.len());
+    primes
+}
+
+// Uniformly random number of at most `bits` bits
+fn random_bits(bits: usize) -> BigUint {
+    let mut bytes = vec![0; bits.div_ceil(8)];
+    rand::thread_rng().fill(&mut bytes[..]);
+
+    if !bits.is_multiple_of(8) {
+        bytes[0] &= (1 << (bits % 8)) - 1;
+    }
+
+    BigUint::from_be_bytes(&bytes)
+}
+
+// Uniformly random number in [low, high] by rejection sampling
+fn random_range(low: &BigUint, high: &BigUint) -> BigUint {
+    let range = high - low;
+    loop {
+        let candidate = random_bits(range.bits());
+        if candidate <= range {
+            return &candidate + low;
+        }
+    }
+}
+
+pub fn is_probable_prime(n: &BigUint, rounds: usize) -> bool {
+    let two = BigUint::from_u128(2);
+    if *n < two {
+        return false;
+    }
+
+    // Trial division, also settles every n below the largest small prime squared
+    for prime in SMALL_PRIMES {
+        let prime = BigUint::from_u128(prime as u128);
+        if *n == prime {
+            return true;
+        }
+        if (n % &prime).is_zero() {
+            return false;
+        }
+    }
+
+    miller_rabin(n, rounds)
+}
+
+// Candidates for key generation are secret, so the exponentiation and squarings, which
+// touch every bit of n, go through the Montgomery ladder. The branches still reveal s,
+// the number of trailing zero bits of n - 1, and where -1 turns up in each round, and
+// trial division takes as long as the CPU's division does. Candidates that fail are
+// discarded, so this leaks a few bits of the kept prime, far from the half of its bits
+// that factoring the modulus would need.
+fn miller_rabin(n: &BigUint, rounds: usize) -> bool {
+    let montgomery = Montgomery::new(n).unwrap();
+    let one = BigUint::one();
+    let minus_one = n - &one;
+
+    // n - 1 = d * 2^s with d odd
+    let mut s = 0;
+    while !minus_one.bit(s) {
+        s += 1;
+    }
+    let d = &minus_one >> s;
+
+    let low = BigUint::from_u128(2);
+    let high = n - &low;
+
+    'witness: for _ in 0..rounds {
+        let a = random_range(&low, &high);
+        let mut x = montgomery.pow_constant_time(&a, &d);
+        if x == one || x == minus_one {
+            continue;
+        }
+
+        for _ in 1..s {
+            x = montgomery.square(&x);
+            if x == minus_one {
+                continue 'witness;
+            }
+        }
+
+        return false;
+    }
+
+    true
+}
+
+// Random prime of exactly `bits` bits with the top two bits set, so the product of
+// two such primes has exactly the sum of their lengths
+pub fn generate_prime(bits: usize) -> BigUint {
+    assert!(bits >= 16, "primes must be at least 16 bits");
+
+    let top = &BigUint::from_u128(3) << (bits - 2);
+    loop {
+        let candidate = &(&top + &(&random_bits(bits - 3) << 1)) + &BigUint::one();
+
+        if is_probable_prime(&candidate, ROUNDS) {
+            return candidate;
+        }
+    }
+}
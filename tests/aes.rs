@@ -0,0 +1,170 @@
+use rand::Rng;
+use spectrum::cryptography::aes::{AESBackend, InitKey, AES, CTR, GCM, GCMSIV, XTS};
+use spectrum::cryptography::CryptographicAlgorithm;
+use spectrum::format::{hex_to_u8, u8_to_hex};
+
+const BACKENDS: [AESBackend; 4] = [
+    AESBackend::Reference,
+    AESBackend::TTable,
+    AESBackend::ConstantTime,
+    AESBackend::AESNI,
+];
+
+fn check(key: &str, plaintext: &str, ciphertext: &str) {
+    for backend in BACKENDS {
+        let aes = AES::with_backend(InitKey::from_slice(&hex_to_u8(key)).unwrap(), backend);
+        if backend.is_supported() {
+            assert_eq!(aes.backend(), backend);
+        }
+
+        assert_eq!(u8_to_hex(aes.encrypt(&hex_to_u8(plaintext))), ciphertext);
+        assert_eq!(u8_to_hex(aes.decrypt(&hex_to_u8(ciphertext))), plaintext);
+    }
+}
+
+// Test vectors from FIPS 197 appendix C
+#[test]
+fn aes128() {
+    check(
+        "000102030405060708090a0b0c0d0e0f",
+        "00112233445566778899aabbccddeeff",
+        "69c4e0d86a7b0430d8cdb78070b4c55a",
+    );
+}
+
+#[test]
+fn aes192() {
+    check(
+        "000102030405060708090a0b0c0d0e0f1011121314151617",
+        "00112233445566778899aabbccddeeff",
+        "dda97ca4864cdfe06eaf70a0ec0d7191",
+    );
+}
+
+#[test]
+fn aes256() {
+    check(
+        "000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f",
+        "00112233445566778899aabbccddeeff",
+        "8ea2b7ca516745bfeafc49904b496089",
+    );
+}
+
+#[test]
+fn backends_agree_on_random_input() {
+    let mut rng = rand::thread_rng();
+
+    for size in [16, 24, 32] {
+        for _ in 0..20 {
+            let key: Vec<u8> = (0..size).map(|_| rng.gen()).collect();
+            let message: Vec<u8> = (0..256).map(|_| rng.gen()).collect();
+
+            let outputs: Vec<(Vec<u8>, Vec<u8>)> = BACKENDS
+                .iter()
+                .map(|backend| {
+                    let aes = AES::with_backend(InitKey::from_slice(&key).unwrap(), *backend);
+                    (aes.encrypt(&message), aes.decrypt(&message))
+                })
+                .collect();
+
+            for output in &outputs[1..] {
+                assert_eq!(output, &outputs[0]);
+            }
+        }
+    }
+}
+
+#[test]
+fn unsupported_backend_falls_back() {
+    let aes = AES::with_backend(InitKey::from_slice(&[0; 16]).unwrap(), AESBackend::AESNI);
+
+    assert!(aes.backend().is_supported());
+    assert_eq!(
+        AES::from_init_key(InitKey::from_slice(&[0; 16]).unwrap()).backend(),
+        AESBackend::detect()
+    );
+}
+
+#[test]
+fn pipelined_blocks_match_single_blocks() {
+    let mut rng = rand::thread_rng();
+
+    for backend in BACKENDS {
+        for size in [16, 24, 32] {
+            let key: Vec<u8> = (0..size).map(|_| rng.gen()).collect();
+            let aes = AES::with_backend(InitKey::from_slice(&key).unwrap(), backend);
+
+            for count in [1, 7, 8, 9, 17] {
+                let blocks: Vec<[u8; 16]> = (0..count).map(|_| rng.gen()).collect();
+
+                let mut expected = blocks.clone();
+                expected
+                    .iter_mut()
+                    .for_each(|block| aes.encrypt_block(block));
+
+                let mut pipelined = blocks.clone();
+                aes.encrypt_blocks(&mut pipelined);
+                assert_eq!(pipelined, expected);
+
+                aes.decrypt_blocks(&mut pipelined);
+                assert_eq!(pipelined, blocks);
+            }
+        }
+    }
+}
+
+#[test]
+fn bulk_modes_agree_across_backends() {
+    let mut rng = rand::thread_rng();
+
+    for _ in 0..10 {
+        let key: Vec<u8> = (0..32).map(|_| rng.gen()).collect();
+        let iv: [u8; 16] = rng.gen();
+        let nonce: [u8; 12] = rng.gen();
+        let length = rng.gen_range(0..1000);
+        let message: Vec<u8> = (0..length).map(|_| rng.gen()).collect();
+        let offset = rng.gen_range(0..100);
+
+        let outputs: Vec<(Vec<u8>, Vec<u8>, Vec<u8>)> = BACKENDS
+            .iter()
+            .map(|backend| {
+                let aes = || AES::with_backend(InitKey::from_slice(&key).unwrap(), *backend);
+                (
+                    CTR::new(aes(), iv)
+                        .apply_keystream(offset, &message)
+                        .unwrap(),
+                    GCM::new(aes()).encrypt(&nonce, b"aad", &message).unwrap(),
+                    GCMSIV::new(aes())
+                        .unwrap()
+                        .encrypt(&nonce, b"aad", &message),
+                )
+            })
+            .collect();
+
+        for output in &outputs[1..] {
+            assert_eq!(output, &outputs[0]);
+        }
+    }
+}
+
+#[test]
+fn xts_uses_chosen_backend() {
+    let mut rng = rand::thread_rng();
+    let key: Vec<u8> = (0..64).map(|_| rng.gen()).collect();
+    let sector: Vec<u8> = (0..512).map(|_| rng.gen()).collect();
+
+    let outputs: Vec<Vec<u8>> = BACKENDS
+        .iter()
+        .filter(|backend| backend.is_supported())
+        .map(|backend| {
+            let xts = XTS::with_backend(&key, *backend).unwrap();
+            assert_eq!(xts.backend(), *backend);
+            xts.encrypt_sector(7, &sector).unwrap()
+        })
+        .collect();
+
+    for output in &outputs[1..] {
+        assert_eq!(output, &outputs[0]);
+    }
+    assert_eq!(XTS::new(&key).unwrap().backend(), AESBackend::detect());
+}
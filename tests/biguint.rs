@@ -0,0 +1,166 @@
+use rand::Rng;
+use spectrum::format::{hex_to_u8, u8_to_hex};
+use spectrum::math::BigUint;
+
+fn big(hex: &str) -> BigUint {
+    BigUint::from_be_bytes(&hex_to_u8(hex))
+}
+
+fn hex(number: &BigUint) -> String {
+    u8_to_hex(number.to_be_bytes())
+}
+
+// Random 2048-bit and 1000-bit operands, results computed with Python integers
+const A: &str = "85013278ed8dbab6cf0141301ff7f21216a591f4d1484c93bdb39a6227a1d40205f204ab5e5284e4f01aea92f3b3eb97a618d1431da5b627b1a470b67f5f96b68a473a6a5434b6b5f4ee9a0308b8d0a0711c718a9daaf919682204bbe0029715c54cb0e4bd1aa3f1fed0c435ff602bda6fd5ca040ad67e72b1a4a4f93b91e572ebe718df3b74e9fbc056855fcb33444b25199d6011bb55f86d9deeee95da5109eeca8c285efcea76039d74ed00d0722dc9d4d0203c6e3096870d6796814d31e82eff2f128330550ff69542b8cecf8a1779827b7acaea0518fd5e5ee3374cb756d7e11b1b7aa6540d48007596a28f5b376b0404f2b09490b86b01a1c12a3a2107";
+const B: &str = "92bad1612afd23406594ad0fa3e5bb876ac34660fcf305be92c13a13f3a87266a2e4daf1c3cd8bbe9cf8013ebbac7dc96b356455533287533dc7bf13aa131a83dc3c202fb0d1f4fb87ddaaad70784e1ea40981fa59aa4486552fd940bb26ae54ee7c1589b466be6e5457c9b2c0ba7c3a758d500f76293dc20674002b8e";
+const SUM: &str = "85013278ed8dbab6cf0141301ff7f21216a591f4d1484c93bdb39a6227a1d40205f204ab5e5284e4f01aea92f3b3eb97a618d1431da5b627b1a470b67f5f96b68a473a6a5434b6b5f4ee9a0308b8d0a0711c718a9daaf919682204bbe0029715c54cb0e4bd1aa3f1fed0c435ff602bda6fd5ca040ad67e72b1a4a4f93b91e572ebe71971f6464b26bd79c5c55fe053ef0ad524cad501b6f560a3ad81571464fd973cf2cb43d7dc39d1293389f8d1b0e97652998b71d285e9b994bad4490c45924219b2eebf5084c0c88a3e40ac7a3787f1d09a1ed46bff72a7a2e5386725f811fe8f7009f6bbddc1aebee3eafa590df825803f683de4a02e943f63c79e3a4c95";
+const DIFFERENCE: &str = "85013278ed8dbab6cf0141301ff7f21216a591f4d1484c93bdb39a6227a1d40205f204ab5e5284e4f01aea92f3b3eb97a618d1431da5b627b1a470b67f5f96b68a473a6a5434b6b5f4ee9a0308b8d0a0711c718a9daaf919682204bbe0029715c54cb0e4bd1aa3f1fed0c435ff602bda6fd5ca040ad67e72b1a4a4f93b91e572ebe7184c80a388d0c33344fa368634a73f5e15f54e74f4fb7a98305bd4a03d16465825857a21f8b23611b65008cf33721d5706b50709db4354861458b98e1e3e1be4ab364710255f24a04730f124dca701345cd6c1680abf5319d88e0773769bb132c62cfe90ca58e14207424ac5a876b087ca7d2344814241c3dfbab639f579";
+const PRODUCT: &str = "4c3bbe701955fdfffa7a0049607221561de6cf6b9ef7aee9372e783c776447df1a0354dfb225aeb01f316f8f41c3f20736d6f38286d1e5d89dd11d65a0a914d64fec85493dde6c0f5403f6dd6d033369478ac701011694bc347ed5de910c8bb916d8c0ac4d8caa7717bf571fa2854accc50aeedd46b00bbeab34d05639fabaec4fda8c157cde817d99679b98d4d8fed43bc1217164c777bb5f75253c5949d3d62681a35f05efd1ca5395fe2cc32dce88bd248447e964d36ada2f44f739819d01ae936a2deefcf3e6219d56df16720f156fcb09d83a29853f5b3cd122e71acc5c171529a9f4a28e5f1eecd1ef754ad0babe5446a48cc1f7daa0ba2f018d4ae85323c2c9a0d32cc6d3991d5c37600e146d5e327e7b326fe00874de32cee9787b44046594a9f46a8ce227f343b76c27a3af4881bbbc1faa4aed30d9b2c705e9e6fa6cad49e5eb20e71423176da3bcca49efdfed40372b5c9544491beb1fa914e02f53d61b37c7bbaa97ed3a817e2aa89980a0bc872b753044965e5bca7ee2";
+const QUOTIENT: &str = "e80dc58fe4df5d0fcac053f4989bf39ade8ff9adc86cc7f3e0f248fb0502fa6b0d35235ef21a6cb1a491d09e5c82f626577f533cdb8badf592f042a722c8d5630121362dd1303701df6e2d187b62dbc53ff2622bebb32589303328f5483bdd5f054edf943c467580bdc6aed100867dee72c04bc3c335eee78f431c68dc51fd7f16a59e";
+const REMAINDER: &str = "7e864be532b253e20becd676157c71b29ef8975d5ed8769d9300a7f21f2be7ddec9c406b63607656c8746794e5ffaca1defb64eb332a259bde3e7ffcc977cf8b4c8c5fb3c62bab040084555086a5ae2c68f751f551ef581b90f891760fa48f0f5b744078987bf44e6bda41603588fe5c9111c1343278c0b20c45d8b963";
+const SHIFTED_LEFT: &str = "10a0264f1db1b756d9e0282603fefe4242d4b23e9a29099277b6734c44f43a8040be40956bca509c9e035d525e767d72f4c31a2863b4b6c4f6348e16cfebf2d6d148e74d4a8696d6be9dd34061171a140e238e3153b55f232d0440977c0052e2b8a9961c97a3547e3fda1886bfec057b4dfab940815acfce5634949f27723cae5d7ce31be76e9d3f780ad0abf966688964a333ac02376abf0db3bdddd2bb4a213dd951850bdf9d4ec073ae9da01a0e45b93a9a04078dc612d0e1acf2d029a63d05dfe5e250660aa1fed2a85719d9f142ef304f6f595d40a31fabcbdc66e996eadafc23636f54ca81a9000eb2d451eb66ed60809e561292170d60343825474420e0000000000000000000";
+const SHIFTED_RIGHT: &str = "21404c9e3b636eadb3c0504c07fdfc8485a9647d34521324ef6ce69889e87500817c812ad794a1393c06baa4bcecfae5e9863450c7696d89ec691c2d9fd7e5ada291ce9a950d2dad7d3ba680c22e34281c471c62a76abe465a08812ef800a5c571532c392f46a8fc7fb4310d7fd80af69bf5728102b59f9cac69293e4ee4795cbaf9c637cedd3a7ef015a157f2ccd112c9466758046ed57e1b677bbba57694427bb2a30a17bf3a9d80e75d3b40341c8b727534080f1b8c25a1c359e5a0534c7a0bbfcbc4a0cc1543fda550ae33b3e285de609edeb2ba81463f5797b8cdd32dd5b5f846c6dea9950352001d65a8a3d6cd";
+
+#[test]
+fn arithmetic_matches_reference() {
+    let (a, b) = (big(A), big(B));
+
+    assert_eq!(hex(&(&a + &b)), SUM);
+    assert_eq!(hex(&(&b + &a)), SUM);
+    assert_eq!(hex(&(&a - &b)), DIFFERENCE);
+    assert_eq!(hex(&(&a * &b)), PRODUCT);
+    assert_eq!(hex(&(&a / &b)), QUOTIENT);
+    assert_eq!(hex(&(&a % &b)), REMAINDER);
+    assert_eq!(hex(&(&a << 77)), SHIFTED_LEFT);
+    assert_eq!(hex(&(&a >> 130)), SHIFTED_RIGHT);
+}
+
+#[test]
+fn comparison() {
+    let (a, b) = (big(A), big(B));
+
+    assert!(a > b);
+    assert!(b < a);
+    assert_eq!(a.clone(), a);
+    assert!(big("0100") > big("ff"));
+    assert!(BigUint::zero() < BigUint::one());
+    assert_eq!(b.checked_sub(&a), None);
+}
+
+#[test]
+fn byte_conversion() {
+    assert_eq!(BigUint::zero().to_be_bytes(), vec![0]);
+    assert_eq!(BigUint::from_be_bytes(&[0, 0, 0]), BigUint::zero());
+    assert_eq!(big("000102").to_be_bytes(), vec![1, 2]);
+    assert_eq!(big("0102").to_be_bytes_padded(4), Some(vec![0, 0, 1, 2]));
+    assert_eq!(big("010203").to_be_bytes_padded(2), None);
+    assert_eq!(big(A).bits(), 2048);
+    assert_eq!(hex(&big(A)), A);
+
+    let value = 0x0123456789abcdef_fedcba9876543210;
+    assert_eq!(BigUint::from_u128(value).to_u128(), Some(value));
+    assert_eq!(big(A).to_u128(), None);
+}
+
+#[test]
+fn bits() {
+    let number = big("8001");
+
+    assert!(number.bit(0));
+    assert!(!number.bit(1));
+    assert!(number.bit(15));
+    assert!(!number.bit(1000));
+    assert!(number.is_odd());
+    assert!(!BigUint::zero().is_odd());
+    assert!(BigUint::zero().is_zero());
+}
+
+#[test]
+fn matches_u128_arithmetic() {
+    let mut rng = rand::thread_rng();
+
+    for _ in 0..1000 {
+        let a: u128 = rng.gen::<u128>() >> rng.gen_range(0..128);
+        let b: u128 = (rng.gen::<u128>() >> rng.gen_range(0..128)).max(1);
+        let shift = rng.gen_range(0..64);
+        let (x, y) = (BigUint::from_u128(a), BigUint::from_u128(b));
+
+        assert_eq!((&x / &y).to_u128(), Some(a / b));
+        assert_eq!((&x % &y).to_u128(), Some(a % b));
+        assert_eq!((&x >> shift).to_u128(), Some(a >> shift));
+        assert_eq!(((&x >> 64) << 64).to_u128(), Some(a >> 64 << 64));
+        assert_eq!(x.cmp(&y), a.cmp(&b));
+
+        let (sum, overflow) = a.overflowing_add(b);
+        if !overflow {
+            assert_eq!((&x + &y).to_u128(), Some(sum));
+            assert_eq!((&BigUint::from_u128(sum) - &y).to_u128(), Some(a));
+        }
+        if (a >> 64) == 0 && (b >> 64) == 0 {
+            assert_eq!((&x * &y).to_u128(), Some(a * b));
+        }
+    }
+}
+
+#[test]
+fn division_identity() {
+    let mut rng = rand::thread_rng();
+
+    for _ in 0..200 {
+        let a = BigUint::from_be_bytes(
+            &(0..rng.gen_range(1..512))
+                .map(|_| rng.gen())
+                .collect::<Vec<u8>>(),
+        );
+        let b = BigUint::from_be_bytes(
+            &(0..rng.gen_range(1..256))
+                .map(|_| rng.gen())
+                .collect::<Vec<u8>>(),
+        );
+        if b.is_zero() {
+            continue;
+        }
+
+        let (quotient, remainder) = a.div_rem(&b);
+        assert!(remainder < b);
+        assert_eq!(&(&quotient * &b) + &remainder, a);
+    }
+}
+
+// Limbs close to the edges reach the rarely taken correction steps of the long division
+#[test]
+fn division_identity_edge_limbs() {
+    let edges = [0, 1, 0x7fffffffffffffff, 0x8000000000000000, u64::MAX];
+    let numbers: Vec<BigUint> = (0..5usize.pow(4))
+        .map(|mut index| {
+            let mut bytes = Vec::new();
+            for _ in 0..4 {
+                bytes.extend_from_slice(&edges[index % 5].to_be_bytes());
+                index /= 5;
+            }
+            BigUint::from_be_bytes(&bytes)
+        })
+        .collect();
+
+    for a in &numbers {
+        for b in numbers.iter().filter(|b| !b.is_zero()) {
+            let (quotient, remainder) = a.div_rem(b);
+            assert!(&remainder < b);
+            assert_eq!(&(&quotient * b) + &remainder, *a);
+        }
+    }
+}
+
+// Hacker's Delight test case for the add back step of algorithm D, scaled to 64-bit limbs
+#[test]
+fn division_add_back() {
+    let (quotient, remainder) =
+        big("7fffffffffffffff800000000000000000000000000000000000000000000000")
+            .div_rem(&big("800000000000000000000000000000000000000000000001"));
+
+    assert_eq!(hex(&quotient), "fffffffffffffffe");
+    assert_eq!(
+        hex(&remainder),
+        "7fffffffffffffffffffffffffffffff0000000000000002"
+    );
+}
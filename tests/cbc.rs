@@ -0,0 +1,102 @@
+use spectrum::cryptography::aes::{AESError, InitKey, AES, CBC};
+use spectrum::format::{hex_to_u8, u8_to_hex};
+
+const IV: &str = "000102030405060708090a0b0c0d0e0f";
+const PLAINTEXT: &str = "6bc1bee22e409f96e93d7e117393172aae2d8a571e03ac9c9eb76fac45af8e5130c81c46a35ce411e5fbc1191a0a52eff69f2445df4f9b17ad2b417be66c3710";
+
+fn cbc(key: &str, iv: &str) -> CBC {
+    let aes = AES::from_init_key(InitKey::from_slice(&hex_to_u8(key)).unwrap());
+    let mut block = [0; 16];
+    block.copy_from_slice(&hex_to_u8(iv));
+    CBC::new(aes, block)
+}
+
+// Test vectors from NIST SP 800-38A F.2; the final block is the PKCS#7 padding
+fn check_sp800_38a(key: &str, expected: &str) {
+    let cbc = cbc(key, IV);
+    let cipher = cbc.encrypt(&hex_to_u8(PLAINTEXT));
+
+    assert_eq!(cipher.len(), 80);
+    assert_eq!(u8_to_hex(cipher[..64].to_vec()), expected);
+    assert_eq!(u8_to_hex(cbc.decrypt(&cipher).unwrap()), PLAINTEXT);
+}
+
+#[test]
+fn cbc_aes128_sp800_38a() {
+    check_sp800_38a(
+        "2b7e151628aed2a6abf7158809cf4f3c",
+        "7649abac8119b246cee98e9b12e9197d5086cb9b507219ee95db113a917678b273bed6b8e3c1743b7116e69e222295163ff1caa1681fac09120eca307586e1a7",
+    );
+}
+
+#[test]
+fn cbc_aes192_sp800_38a() {
+    check_sp800_38a(
+        "8e73b0f7da0e6452c810f32b809079e562f8ead2522c6b7b",
+        "4f021db243bc633d7178183a9fa071e8b4d9ada9ad7dedf4e5e738763f69145a571b242012fb7ae07fa9baac3df102e008b0e27988598881d920a9e64f5615cd",
+    );
+}
+
+#[test]
+fn cbc_aes256_sp800_38a() {
+    check_sp800_38a(
+        "603deb1015ca71be2b73aef0857d77811f352c073b6108d72d9810a30914dff4",
+        "f58c4c04d6e5f1ba779eabfb5f7bfbd69cfc4e967edb808d679f777bc6702c7d39f23369a9d9bacfa530e26304231461b2eb05e2c39be9fcda6c19078c6a9d1b",
+    );
+}
+
+// Ciphertexts produced by `openssl enc -aes-*-cbc -K <key> -iv <iv>`
+#[test]
+fn cbc_openssl_interop() {
+    let cbc256 = cbc(
+        "603deb1015ca71be2b73aef0857d77811f352c073b6108d72d9810a30914dff4",
+        IV,
+    );
+    let message = b"The quick brown fox jumps over the lazy dog";
+    let expected = "993f48c817946d0ccba1d7c53813cf8441e613d2cb47645dc1825884add9b9c9b0b2a3596ecd601df726bbaa5c087b72";
+    assert_eq!(u8_to_hex(cbc256.encrypt(message)), expected);
+    assert_eq!(cbc256.decrypt(&hex_to_u8(expected)).unwrap(), message);
+
+    let cbc128 = cbc("2b7e151628aed2a6abf7158809cf4f3c", IV);
+    assert_eq!(
+        u8_to_hex(cbc128.encrypt(b"")),
+        "c84af0b613435d5d9182801a9bd9320b"
+    );
+    assert_eq!(
+        u8_to_hex(cbc128.encrypt(b"0123456789abcdef")),
+        "64768548007aef9f3d258e5c34cdc21bde0a1268436e159434fc21de3696d928"
+    );
+}
+
+#[test]
+fn cbc_binary_round_trip() {
+    let cbc = cbc("2b7e151628aed2a6abf7158809cf4f3c", IV);
+
+    for length in 0..=48 {
+        let message: Vec<u8> = (0..length).map(|i| (i % 3) as u8).collect();
+        assert_eq!(cbc.decrypt(&cbc.encrypt(&message)).unwrap(), message);
+    }
+}
+
+#[test]
+fn cbc_invalid_padding() {
+    let cbc = cbc("2b7e151628aed2a6abf7158809cf4f3c", IV);
+    let mut cipher = cbc.encrypt(b"attack at dawn");
+    cipher[15] ^= 0x01;
+
+    assert!(matches!(
+        cbc.decrypt(&cipher),
+        Err(AESError::InvalidPadding)
+    ));
+}
+
+#[test]
+fn cbc_invalid_length() {
+    let cbc = cbc("2b7e151628aed2a6abf7158809cf4f3c", IV);
+
+    assert!(matches!(cbc.decrypt(&[]), Err(AESError::InvalidLength)));
+    assert!(matches!(
+        cbc.decrypt(&[0; 17]),
+        Err(AESError::InvalidLength)
+    ));
+}
@@ -0,0 +1,202 @@
+use spectrum::cryptography::aes::{AESError, InitKey, AES, CCM};
+use spectrum::format::{hex_to_u8, u8_to_hex};
+
+fn ccm(key: &str, tag_size: usize, nonce_size: usize) -> CCM {
+    let aes = AES::from_init_key(InitKey::from_slice(&hex_to_u8(key)).unwrap());
+    CCM::new(aes, tag_size, nonce_size).unwrap()
+}
+
+fn check(ccm: &CCM, nonce: &str, aad: &str, plaintext: &str, expected: &str) {
+    let cipher = ccm
+        .encrypt(&hex_to_u8(nonce), &hex_to_u8(aad), &hex_to_u8(plaintext))
+        .unwrap();
+
+    assert_eq!(u8_to_hex(cipher.clone()), expected);
+    assert_eq!(
+        u8_to_hex(
+            ccm.decrypt(&hex_to_u8(nonce), &hex_to_u8(aad), &cipher)
+                .unwrap()
+        ),
+        plaintext
+    );
+}
+
+// Packet vectors from RFC 3610 section 8; the header is the associated data
+
+#[test]
+fn ccm_rfc3610_packet_vector_1() {
+    check(
+        &ccm("c0c1c2c3c4c5c6c7c8c9cacbcccdcecf", 8, 13),
+        "00000003020100a0a1a2a3a4a5",
+        "0001020304050607",
+        "08090a0b0c0d0e0f101112131415161718191a1b1c1d1e",
+        "588c979a61c663d2f066d0c2c0f989806d5f6b61dac38417e8d12cfdf926e0",
+    );
+}
+
+#[test]
+fn ccm_rfc3610_packet_vector_2() {
+    check(
+        &ccm("c0c1c2c3c4c5c6c7c8c9cacbcccdcecf", 8, 13),
+        "00000004030201a0a1a2a3a4a5",
+        "0001020304050607",
+        "08090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f",
+        "72c91a36e135f8cf291ca894085c87e3cc15c439c9e43a3ba091d56e10400916",
+    );
+}
+
+#[test]
+fn ccm_rfc3610_packet_vector_3() {
+    check(
+        &ccm("c0c1c2c3c4c5c6c7c8c9cacbcccdcecf", 8, 13),
+        "00000005040302a0a1a2a3a4a5",
+        "0001020304050607",
+        "08090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f20",
+        "51b1e5f44a197d1da46b0f8e2d282ae871e838bb64da8596574adaa76fbd9fb0c5",
+    );
+}
+
+#[test]
+fn ccm_rfc3610_packet_vector_7() {
+    check(
+        &ccm("c0c1c2c3c4c5c6c7c8c9cacbcccdcecf", 10, 13),
+        "00000009080706a0a1a2a3a4a5",
+        "0001020304050607",
+        "08090a0b0c0d0e0f101112131415161718191a1b1c1d1e",
+        "0135d1b2c95f41d5d1d4fec185d166b8094e999dfed96c048c56602c97acbb7490",
+    );
+}
+
+#[test]
+fn ccm_rfc3610_packet_vector_8() {
+    check(
+        &ccm("c0c1c2c3c4c5c6c7c8c9cacbcccdcecf", 10, 13),
+        "0000000a090807a0a1a2a3a4a5",
+        "0001020304050607",
+        "08090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f",
+        "7b75399ac0831dd2f0bbd75879a2fd8f6cae6b6cd9b7db24c17b4433f434963f34b4",
+    );
+}
+
+#[test]
+fn ccm_aes256_tag_and_nonce_sizes() {
+    let key = "603deb1015ca71be2b73aef0857d77811f352c073b6108d72d9810a30914dff4";
+    let plaintext =
+        "54686520717569636b2062726f776e20666f78206a756d7073206f76657220746865206c617a7920646f67";
+    let aad = "737065637472756d";
+
+    check(
+        &ccm(key, 4, 7),
+        "10111213141516",
+        aad,
+        plaintext,
+        "dc8eee694546eee2f474207aa1754359c2644b423a43397e553ceab5d59276fe9553a3496c900832753399abe0d287",
+    );
+
+    check(
+        &ccm(key, 6, 8),
+        "1011121314151617",
+        aad,
+        plaintext,
+        "44b2830874214500910ba49c5282f3c517ba20bdc1ae054decb00a709b87609240edcf21d89c765a8edf93c17002c30478",
+    );
+
+    check(
+        &ccm(key, 8, 10),
+        "10111213141516171819",
+        aad,
+        plaintext,
+        "5819b0bbcf6cb73a7ce125c7cefc485465562f7480a2e7f03636de0e94c48bc5d3be225ae08cf9753db5f60295e1d8433f9175",
+    );
+
+    check(
+        &ccm(key, 12, 12),
+        "101112131415161718191a1b",
+        aad,
+        plaintext,
+        "8c6b9c5d8b3215dafd399e83d538f0d7b8958e1ade6fde3d4c7fa031a0fd4f97fb3cbd904487af6c56145066eea993326af535934d6f43",
+    );
+
+    check(
+        &ccm(key, 14, 11),
+        "101112131415161718191a",
+        aad,
+        plaintext,
+        "fbb0f10a38a69ddc425d292eee35f31146bedd2c24b741a4aaf15b703f084b5e737083c4582bd64fa43ab40d468245b1106e9baaed0e5b0f82",
+    );
+
+    check(
+        &ccm(key, 16, 13),
+        "101112131415161718191a1b1c",
+        aad,
+        plaintext,
+        "64edc2d192ae0c3c5d342c061cf4cd992658edf52dbcdaf31fab37da7f600c120b1aa3ce032762009f28c4bef31321fcd8b0b58fa5c2f2b0516d73",
+    );
+
+    check(
+        &ccm(key, 16, 7),
+        "10111213141516",
+        aad,
+        plaintext,
+        "dc8eee694546eee2f474207aa1754359c2644b423a43397e553ceab5d59276fe9553a3496c9008327533998764ee2c32542a66d2dd2d4208fe0d76",
+    );
+
+    check(
+        &ccm(key, 16, 13),
+        "101112131415161718191a1b1c",
+        "",
+        plaintext,
+        "64edc2d192ae0c3c5d342c061cf4cd992658edf52dbcdaf31fab37da7f600c120b1aa3ce032762009f28c405638e48f9b23410269988d4d7bab258",
+    );
+}
+
+#[test]
+fn ccm_tampering_is_detected() {
+    let ccm = ccm("c0c1c2c3c4c5c6c7c8c9cacbcccdcecf", 8, 13);
+    let nonce = hex_to_u8("00000003020100a0a1a2a3a4a5");
+    let cipher = ccm.encrypt(&nonce, b"header", b"attack at dawn").unwrap();
+
+    for i in 0..cipher.len() {
+        let mut tampered = cipher.clone();
+        tampered[i] ^= 0x01;
+        assert!(matches!(
+            ccm.decrypt(&nonce, b"header", &tampered),
+            Err(AESError::AuthenticationFailed)
+        ));
+    }
+
+    assert!(matches!(
+        ccm.decrypt(&nonce, b"Header", &cipher),
+        Err(AESError::AuthenticationFailed)
+    ));
+    assert!(matches!(
+        ccm.decrypt(&nonce, b"header", &cipher[..7]),
+        Err(AESError::InvalidLength)
+    ));
+}
+
+#[test]
+fn ccm_invalid_parameters() {
+    let key = hex_to_u8("c0c1c2c3c4c5c6c7c8c9cacbcccdcecf");
+    let aes = || AES::from_init_key(InitKey::from_slice(&key).unwrap());
+
+    for (tag_size, nonce_size) in [(2, 13), (5, 13), (18, 13), (8, 6), (8, 14)] {
+        assert!(matches!(
+            CCM::new(aes(), tag_size, nonce_size),
+            Err(AESError::InccorectSize)
+        ));
+    }
+
+    let ccm = CCM::new(aes(), 8, 13).unwrap();
+    assert!(matches!(
+        ccm.encrypt(&[0; 12], &[], b"message"),
+        Err(AESError::InccorectSize)
+    ));
+
+    // A 13-byte nonce leaves two bytes for the message length
+    assert!(ccm.encrypt(&[0; 13], &[], &vec![0; 0xffff]).is_ok());
+    assert!(matches!(
+        ccm.encrypt(&[0; 13], &[], &vec![0; 0x10000]),
+        Err(AESError::InvalidLength)
+    ));
+}
@@ -0,0 +1,129 @@
+use spectrum::cryptography::aes::{CFBType, InitKey, AES, CFB, OFB};
+use spectrum::cryptography::CryptographicAlgorithm;
+use spectrum::format::{hex_to_u8, u8_to_hex};
+
+const IV: [u8; 16] = [
+    0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0a, 0x0b, 0x0c, 0x0d, 0x0e, 0x0f,
+];
+const PLAINTEXT: &str = "6bc1bee22e409f96e93d7e117393172aae2d8a571e03ac9c9eb76fac45af8e5130c81c46a35ce411e5fbc1191a0a52eff69f2445df4f9b17ad2b417be66c3710";
+const PLAINTEXT_CFB8: &str = "6bc1bee22e409f96e93d7e117393172aae2d";
+
+fn aes(key: &str) -> AES {
+    AES::from_init_key(InitKey::from_slice(&hex_to_u8(key)).unwrap())
+}
+
+fn check(algorithm: &impl CryptographicAlgorithm, plaintext: &str, expected: &str) {
+    assert_eq!(
+        u8_to_hex(algorithm.encrypt(&hex_to_u8(plaintext))),
+        expected
+    );
+    assert_eq!(
+        u8_to_hex(algorithm.decrypt(&hex_to_u8(expected))),
+        plaintext
+    );
+}
+
+// Test vectors from NIST SP 800-38A F.3 and F.4
+
+#[test]
+fn cfb8_aes128_sp800_38a() {
+    check(
+        &CFB::new(aes("2b7e151628aed2a6abf7158809cf4f3c"), IV, CFBType::CFB8),
+        PLAINTEXT_CFB8,
+        "3b79424c9c0dd436bace9e0ed4586a4f32b9",
+    );
+}
+
+#[test]
+fn cfb128_aes128_sp800_38a() {
+    check(
+        &CFB::new(aes("2b7e151628aed2a6abf7158809cf4f3c"), IV, CFBType::CFB128),
+        PLAINTEXT,
+        "3b3fd92eb72dad20333449f8e83cfb4ac8a64537a0b3a93fcde3cdad9f1ce58b26751f67a3cbb140b1808cf187a4f4dfc04b05357c5d1c0eeac4c66f9ff7f2e6",
+    );
+}
+
+#[test]
+fn ofb_aes128_sp800_38a() {
+    check(
+        &OFB::new(aes("2b7e151628aed2a6abf7158809cf4f3c"), IV),
+        PLAINTEXT,
+        "3b3fd92eb72dad20333449f8e83cfb4a7789508d16918f03f53c52dac54ed8259740051e9c5fecf64344f7a82260edcc304c6528f659c77866a510d9c1d6ae5e",
+    );
+}
+
+#[test]
+fn cfb8_aes192_sp800_38a() {
+    check(
+        &CFB::new(
+            aes("8e73b0f7da0e6452c810f32b809079e562f8ead2522c6b7b"),
+            IV,
+            CFBType::CFB8,
+        ),
+        PLAINTEXT_CFB8,
+        "cda2521ef0a905ca44cd057cbf0d47a0678a",
+    );
+}
+
+#[test]
+fn cfb128_aes192_sp800_38a() {
+    check(
+        &CFB::new(aes("8e73b0f7da0e6452c810f32b809079e562f8ead2522c6b7b"), IV, CFBType::CFB128),
+        PLAINTEXT,
+        "cdc80d6fddf18cab34c25909c99a417467ce7f7f81173621961a2b70171d3d7a2e1e8a1dd59b88b1c8e60fed1efac4c9c05f9f9ca9834fa042ae8fba584b09ff",
+    );
+}
+
+#[test]
+fn ofb_aes192_sp800_38a() {
+    check(
+        &OFB::new(aes("8e73b0f7da0e6452c810f32b809079e562f8ead2522c6b7b"), IV),
+        PLAINTEXT,
+        "cdc80d6fddf18cab34c25909c99a4174fcc28b8d4c63837c09e81700c11004018d9a9aeac0f6596f559c6d4daf59a5f26d9f200857ca6c3e9cac524bd9acc92a",
+    );
+}
+
+#[test]
+fn cfb8_aes256_sp800_38a() {
+    check(
+        &CFB::new(
+            aes("603deb1015ca71be2b73aef0857d77811f352c073b6108d72d9810a30914dff4"),
+            IV,
+            CFBType::CFB8,
+        ),
+        PLAINTEXT_CFB8,
+        "dc1f1a8520a64db55fcc8ac554844e889700",
+    );
+}
+
+#[test]
+fn cfb128_aes256_sp800_38a() {
+    check(
+        &CFB::new(aes("603deb1015ca71be2b73aef0857d77811f352c073b6108d72d9810a30914dff4"), IV, CFBType::CFB128),
+        PLAINTEXT,
+        "dc7e84bfda79164b7ecd8486985d386039ffed143b28b1c832113c6331e5407bdf10132415e54b92a13ed0a8267ae2f975a385741ab9cef82031623d55b1e471",
+    );
+}
+
+#[test]
+fn ofb_aes256_sp800_38a() {
+    check(
+        &OFB::new(aes("603deb1015ca71be2b73aef0857d77811f352c073b6108d72d9810a30914dff4"), IV),
+        PLAINTEXT,
+        "dc7e84bfda79164b7ecd8486985d38604febdc6740d20b3ac88f6ad82a4fb08d71ab47a086e86eedf39d1c5bba97c4080126141d67f37be8538f5a8be740e484",
+    );
+}
+
+#[test]
+fn cfb128_and_ofb_partial_blocks() {
+    check(
+        &CFB::new(aes("2b7e151628aed2a6abf7158809cf4f3c"), IV, CFBType::CFB128),
+        "6bc1bee22e409f96e93d7e117393172aae2d8a571e03ac9c9eb76fac45af8e5130c81c46a3",
+        "3b3fd92eb72dad20333449f8e83cfb4ac8a64537a0b3a93fcde3cdad9f1ce58b26751f67a3",
+    );
+    check(
+        &OFB::new(aes("2b7e151628aed2a6abf7158809cf4f3c"), IV),
+        "6bc1bee22e409f96e93d7e117393172aae2d8a571e03ac9c9eb76fac45af8e5130c81c46a3",
+        "3b3fd92eb72dad20333449f8e83cfb4a7789508d16918f03f53c52dac54ed8259740051e9c",
+    );
+}
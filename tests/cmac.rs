@@ -0,0 +1,100 @@
+use spectrum::cryptography::aes::{InitKey, AES, CMAC, GMAC};
+use spectrum::format::{hex_to_u8, u8_to_hex};
+
+const MESSAGE: &str = "6bc1bee22e409f96e93d7e117393172aae2d8a571e03ac9c9eb76fac45af8e5130c81c46a35ce411e5fbc1191a0a52eff69f2445df4f9b17ad2b417be66c3710";
+
+fn aes(key: &str) -> AES {
+    AES::from_init_key(InitKey::from_slice(&hex_to_u8(key)).unwrap())
+}
+
+fn check(key: &str, tags: [&str; 4]) {
+    let cmac = CMAC::new(aes(key));
+    let message = hex_to_u8(MESSAGE);
+
+    for (length, tag) in [0, 16, 40, 64].into_iter().zip(tags) {
+        assert_eq!(u8_to_hex(cmac.mac(&message[..length])), tag);
+        assert!(cmac.verify(&message[..length], &hex_to_u8(tag)));
+    }
+}
+
+// Test vectors from RFC 4493 and NIST SP 800-38B
+#[test]
+fn cmac_aes128_subkeys() {
+    let (k1, k2) = CMAC::new(aes("2b7e151628aed2a6abf7158809cf4f3c")).subkeys();
+
+    assert_eq!(u8_to_hex(k1.to_vec()), "fbeed618357133667c85e08f7236a8de");
+    assert_eq!(u8_to_hex(k2.to_vec()), "f7ddac306ae266ccf90bc11ee46d513b");
+}
+
+#[test]
+fn cmac_aes128() {
+    check(
+        "2b7e151628aed2a6abf7158809cf4f3c",
+        [
+            "bb1d6929e95937287fa37d129b756746",
+            "070a16b46b4d4144f79bdd9dd04a287c",
+            "dfa66747de9ae63030ca32611497c827",
+            "51f0bebf7e3b9d92fc49741779363cfe",
+        ],
+    );
+}
+
+#[test]
+fn cmac_aes192() {
+    check(
+        "8e73b0f7da0e6452c810f32b809079e562f8ead2522c6b7b",
+        [
+            "d17ddf46adaacde531cac483de7a9367",
+            "9e99a7bf31e710900662f65e617c5184",
+            "8a1de5be2eb31aad089a82e6ee908b0e",
+            "a1d5df0eed790f794d77589659f39a11",
+        ],
+    );
+}
+
+#[test]
+fn cmac_aes256() {
+    check(
+        "603deb1015ca71be2b73aef0857d77811f352c073b6108d72d9810a30914dff4",
+        [
+            "028962f61b7bf89efc6b551f4667d983",
+            "28a7023f452e8f82bd4bf28d8c37c35c",
+            "aaf3d8f1de5640c232f5b169b9c911e6",
+            "e1992190549f6ed5696a2c056c315410",
+        ],
+    );
+}
+
+#[test]
+fn cmac_rejects_modified_message() {
+    let cmac = CMAC::new(aes("2b7e151628aed2a6abf7158809cf4f3c"));
+    let tag = cmac.mac(b"message");
+
+    assert!(!cmac.verify(b"messagf", &tag));
+    assert!(!cmac.verify(b"message", &tag[..15]));
+}
+
+// GCM test cases 1 and 4 with the plaintext removed, generated with pyca/cryptography
+#[test]
+fn gmac_empty() {
+    let gmac = GMAC::new(aes("00000000000000000000000000000000"));
+
+    assert_eq!(
+        u8_to_hex(gmac.mac(&[0; 12], &[])),
+        "58e2fccefa7e3061367f1d57a4e7455a"
+    );
+}
+
+#[test]
+fn gmac_aad() {
+    let gmac = GMAC::new(aes("feffe9928665731c6d6a8f9467308308"));
+    let nonce = [
+        0xca, 0xfe, 0xba, 0xbe, 0xfa, 0xce, 0xdb, 0xad, 0xde, 0xca, 0xf8, 0x88,
+    ];
+    let aad = hex_to_u8("feedfacedeadbeeffeedfacedeadbeefabaddad2");
+
+    let tag = gmac.mac(&nonce, &aad);
+    assert_eq!(u8_to_hex(tag.clone()), "346434fd51d5cd0c5887ec63e39b907a");
+    assert!(gmac.verify(&nonce, &aad, &tag));
+    assert!(!gmac.verify(&nonce, &aad[1..], &tag));
+}
@@ -0,0 +1,163 @@
+use spectrum::cryptography::aes::{AESError, InitKey, AES, CTR};
+use spectrum::format::{hex_to_u8, u8_to_hex};
+
+const PLAINTEXT: &str = "6bc1bee22e409f96e93d7e117393172aae2d8a571e03ac9c9eb76fac45af8e5130c81c46a35ce411e5fbc1191a0a52eff69f2445df4f9b17ad2b417be66c3710";
+
+fn aes(key: &str) -> AES {
+    AES::from_init_key(InitKey::from_slice(&hex_to_u8(key)).unwrap())
+}
+
+fn block(hex: &str) -> [u8; 16] {
+    let mut block = [0; 16];
+    block.copy_from_slice(&hex_to_u8(hex));
+    block
+}
+
+// Test vectors from NIST SP 800-38A F.5
+fn check_sp800_38a(key: &str, expected: &str) {
+    let ctr = CTR::new(aes(key), block("f0f1f2f3f4f5f6f7f8f9fafbfcfdfeff"));
+
+    assert_eq!(
+        u8_to_hex(ctr.encrypt(&hex_to_u8(PLAINTEXT)).unwrap()),
+        expected
+    );
+    assert_eq!(
+        u8_to_hex(ctr.decrypt(&hex_to_u8(expected)).unwrap()),
+        PLAINTEXT
+    );
+}
+
+#[test]
+fn ctr_aes128_sp800_38a() {
+    check_sp800_38a(
+        "2b7e151628aed2a6abf7158809cf4f3c",
+        "874d6191b620e3261bef6864990db6ce9806f66b7970fdff8617187bb9fffdff5ae4df3edbd5d35e5b4f09020db03eab1e031dda2fbe03d1792170a0f3009cee",
+    );
+}
+
+#[test]
+fn ctr_aes192_sp800_38a() {
+    check_sp800_38a(
+        "8e73b0f7da0e6452c810f32b809079e562f8ead2522c6b7b",
+        "1abc932417521ca24f2b0459fe7e6e0b090339ec0aa6faefd5ccc2c6f4ce8e941e36b26bd1ebc670d1bd1d665620abf74f78a7f6d29809585a97daec58c6b050",
+    );
+}
+
+#[test]
+fn ctr_aes256_sp800_38a() {
+    check_sp800_38a(
+        "603deb1015ca71be2b73aef0857d77811f352c073b6108d72d9810a30914dff4",
+        "601ec313775789a5b7a7f504bbf3d228f443e3ca4d62b59aca84e990cacaf5c52b0930daa23de94ce87017ba2d84988ddfc9c58db67aada613c2dd08457941a6",
+    );
+}
+
+#[test]
+fn ctr_arbitrary_length() {
+    let ctr = CTR::new(
+        aes("2b7e151628aed2a6abf7158809cf4f3c"),
+        block("f0f1f2f3f4f5f6f7f8f9fafbfcfdfeff"),
+    );
+    let message = hex_to_u8(PLAINTEXT);
+
+    for length in 0..message.len() {
+        let cipher = ctr.encrypt(&message[..length]).unwrap();
+        assert_eq!(cipher.len(), length);
+        assert_eq!(ctr.decrypt(&cipher).unwrap(), &message[..length]);
+    }
+}
+
+#[test]
+fn ctr_seek() {
+    let ctr = CTR::new(
+        aes("2b7e151628aed2a6abf7158809cf4f3c"),
+        block("f0f1f2f3f4f5f6f7f8f9fafbfcfdfeff"),
+    );
+    let message = hex_to_u8(PLAINTEXT);
+    let cipher = ctr.encrypt(&message).unwrap();
+
+    for offset in 0..message.len() {
+        assert_eq!(
+            ctr.apply_keystream(offset as u64, &cipher[offset..])
+                .unwrap(),
+            &message[offset..]
+        );
+        assert_eq!(
+            ctr.apply_keystream(offset as u64, &cipher[offset..offset + 1])
+                .unwrap(),
+            &message[offset..offset + 1]
+        );
+    }
+}
+
+#[test]
+fn ctr_full_counter_wraps() {
+    let ctr = CTR::new(aes("2b7e151628aed2a6abf7158809cf4f3c"), [0xff; 16]);
+    assert_eq!(
+        u8_to_hex(ctr.encrypt(&[0; 32]).unwrap()),
+        "8af2860142f786f409307c1a3f7eaaac7df76b0c1ab899b33e42f047b91b546f"
+    );
+}
+
+// The counter wraps within its bytes, but running past its starting value is an error
+#[test]
+fn ctr_split_counter_never_reuses_keystream() {
+    let ctr = CTR::from_nonce(
+        aes("2b7e151628aed2a6abf7158809cf4f3c"),
+        &hex_to_u8("000102030405060708090a0b"),
+        0xffffffff,
+    )
+    .unwrap();
+
+    assert_eq!(
+        u8_to_hex(ctr.counter_block(1).to_vec()),
+        "000102030405060708090a0b00000000"
+    );
+    assert_eq!(
+        u8_to_hex(ctr.encrypt(&[0; 32]).unwrap()),
+        "bdb7c0ef49717942fc68eeb17692fcf494193f8116eb745cfe7465d70c756236"
+    );
+    assert!(matches!(
+        ctr.apply_keystream((1 << 36) - 16, &[0; 32]),
+        Err(AESError::InvalidLength)
+    ));
+}
+
+#[test]
+fn ctr_rejects_exhausted_counter() {
+    let ctr = CTR::from_nonce(
+        aes("2b7e151628aed2a6abf7158809cf4f3c"),
+        &hex_to_u8("000102030405060708090a0b0c0d0e"),
+        0x80,
+    )
+    .unwrap();
+
+    // One counter byte gives 256 blocks, block 256 would repeat block 0
+    assert_eq!(ctr.encrypt(&[0; 4096]).unwrap().len(), 4096);
+    assert!(matches!(
+        ctr.encrypt(&[0; 4097]),
+        Err(AESError::InvalidLength)
+    ));
+    assert!(matches!(
+        ctr.apply_keystream(4096, &[0]),
+        Err(AESError::InvalidLength)
+    ));
+    assert_eq!(ctr.apply_keystream(4095, &[0]).unwrap().len(), 1);
+}
+
+#[test]
+fn ctr_invalid_nonce() {
+    let key = "2b7e151628aed2a6abf7158809cf4f3c";
+
+    assert!(matches!(
+        CTR::from_nonce(aes(key), &[], 0),
+        Err(AESError::InccorectSize)
+    ));
+    assert!(matches!(
+        CTR::from_nonce(aes(key), &[0; 16], 0),
+        Err(AESError::InccorectSize)
+    ));
+    assert!(matches!(
+        CTR::from_nonce(aes(key), &[0; 12], 1 << 32),
+        Err(AESError::InccorectSize)
+    ));
+}
@@ -0,0 +1,179 @@
+use spectrum::cryptography::aes::{AESError, InitKey, AES, GCM};
+use spectrum::format::{hex_to_u8, u8_to_hex};
+
+fn gcm(key: &str) -> GCM {
+    GCM::new(AES::from_init_key(
+        InitKey::from_slice(&hex_to_u8(key)).unwrap(),
+    ))
+}
+
+fn nonce(hex: &str) -> [u8; 12] {
+    let mut nonce = [0; 12];
+    nonce.copy_from_slice(&hex_to_u8(hex));
+    nonce
+}
+
+fn check(key: &str, iv: &str, plaintext: &str, aad: &str, cipher: &str, tag: &str) {
+    let gcm = gcm(key);
+    let output = gcm
+        .encrypt(&nonce(iv), &hex_to_u8(aad), &hex_to_u8(plaintext))
+        .unwrap();
+
+    assert_eq!(u8_to_hex(output.clone()), format!("{cipher}{tag}"));
+    assert_eq!(
+        u8_to_hex(gcm.decrypt(&nonce(iv), &hex_to_u8(aad), &output).unwrap()),
+        plaintext
+    );
+}
+
+// Test cases from "The Galois/Counter Mode of Operation (GCM)", McGrew and Viega, as used by NIST
+
+#[test]
+fn gcm_test_case_1() {
+    check(
+        "00000000000000000000000000000000",
+        "000000000000000000000000",
+        "",
+        "",
+        "",
+        "58e2fccefa7e3061367f1d57a4e7455a",
+    );
+}
+
+#[test]
+fn gcm_test_case_2() {
+    check(
+        "00000000000000000000000000000000",
+        "000000000000000000000000",
+        "00000000000000000000000000000000",
+        "",
+        "0388dace60b6a392f328c2b971b2fe78",
+        "ab6e47d42cec13bdf53a67b21257bddf",
+    );
+}
+
+#[test]
+fn gcm_test_case_3() {
+    check(
+        "feffe9928665731c6d6a8f9467308308",
+        "cafebabefacedbaddecaf888",
+        "d9313225f88406e5a55909c5aff5269a86a7a9531534f7da2e4c303d8a318a721c3c0c95956809532fcf0e2449a6b525b16aedf5aa0de657ba637b391aafd255",
+        "",
+        "42831ec2217774244b7221b784d0d49ce3aa212f2c02a4e035c17e2329aca12e21d514b25466931c7d8f6a5aac84aa051ba30b396a0aac973d58e091473f5985",
+        "4d5c2af327cd64a62cf35abd2ba6fab4",
+    );
+}
+
+#[test]
+fn gcm_test_case_4() {
+    check(
+        "feffe9928665731c6d6a8f9467308308",
+        "cafebabefacedbaddecaf888",
+        "d9313225f88406e5a55909c5aff5269a86a7a9531534f7da2e4c303d8a318a721c3c0c95956809532fcf0e2449a6b525b16aedf5aa0de657ba637b39",
+        "feedfacedeadbeeffeedfacedeadbeefabaddad2",
+        "42831ec2217774244b7221b784d0d49ce3aa212f2c02a4e035c17e2329aca12e21d514b25466931c7d8f6a5aac84aa051ba30b396a0aac973d58e091",
+        "5bc94fbc3221a5db94fae95ae7121a47",
+    );
+}
+
+#[test]
+fn gcm_test_case_7() {
+    check(
+        "000000000000000000000000000000000000000000000000",
+        "000000000000000000000000",
+        "",
+        "",
+        "",
+        "cd33b28ac773f74ba00ed1f312572435",
+    );
+}
+
+#[test]
+fn gcm_test_case_10() {
+    check(
+        "feffe9928665731c6d6a8f9467308308feffe9928665731c",
+        "cafebabefacedbaddecaf888",
+        "d9313225f88406e5a55909c5aff5269a86a7a9531534f7da2e4c303d8a318a721c3c0c95956809532fcf0e2449a6b525b16aedf5aa0de657ba637b39",
+        "feedfacedeadbeeffeedfacedeadbeefabaddad2",
+        "3980ca0b3c00e841eb06fac4872a2757859e1ceaa6efd984628593b40ca1e19c7d773d00c144c525ac619d18c84a3f4718e2448b2fe324d9ccda2710",
+        "2519498e80f1478f37ba55bd6d27618c",
+    );
+}
+
+#[test]
+fn gcm_test_case_13() {
+    check(
+        "0000000000000000000000000000000000000000000000000000000000000000",
+        "000000000000000000000000",
+        "",
+        "",
+        "",
+        "530f8afbc74536b9a963b4f1c4cb738b",
+    );
+}
+
+#[test]
+fn gcm_test_case_14() {
+    check(
+        "0000000000000000000000000000000000000000000000000000000000000000",
+        "000000000000000000000000",
+        "00000000000000000000000000000000",
+        "",
+        "cea7403d4d606b6e074ec5d3baf39d18",
+        "d0d1c8a799996bf0265b98b5d48ab919",
+    );
+}
+
+#[test]
+fn gcm_test_case_15() {
+    check(
+        "feffe9928665731c6d6a8f9467308308feffe9928665731c6d6a8f9467308308",
+        "cafebabefacedbaddecaf888",
+        "d9313225f88406e5a55909c5aff5269a86a7a9531534f7da2e4c303d8a318a721c3c0c95956809532fcf0e2449a6b525b16aedf5aa0de657ba637b391aafd255",
+        "",
+        "522dc1f099567d07f47f37a32a84427d643a8cdcbfe5c0c97598a2bd2555d1aa8cb08e48590dbb3da7b08b1056828838c5f61e6393ba7a0abcc9f662898015ad",
+        "b094dac5d93471bdec1a502270e3cc6c",
+    );
+}
+
+#[test]
+fn gcm_test_case_16() {
+    check(
+        "feffe9928665731c6d6a8f9467308308feffe9928665731c6d6a8f9467308308",
+        "cafebabefacedbaddecaf888",
+        "d9313225f88406e5a55909c5aff5269a86a7a9531534f7da2e4c303d8a318a721c3c0c95956809532fcf0e2449a6b525b16aedf5aa0de657ba637b39",
+        "feedfacedeadbeeffeedfacedeadbeefabaddad2",
+        "522dc1f099567d07f47f37a32a84427d643a8cdcbfe5c0c97598a2bd2555d1aa8cb08e48590dbb3da7b08b1056828838c5f61e6393ba7a0abcc9f662",
+        "76fc6ece0f4e1768cddf8853bb2d551b",
+    );
+}
+
+#[test]
+fn gcm_tampering_is_detected() {
+    let gcm = gcm("feffe9928665731c6d6a8f9467308308");
+    let iv = nonce("cafebabefacedbaddecaf888");
+    let aad = b"header";
+    let cipher = gcm.encrypt(&iv, aad, b"attack at dawn").unwrap();
+
+    for i in 0..cipher.len() {
+        let mut tampered = cipher.clone();
+        tampered[i] ^= 0x80;
+        assert!(matches!(
+            gcm.decrypt(&iv, aad, &tampered),
+            Err(AESError::AuthenticationFailed)
+        ));
+    }
+
+    assert!(matches!(
+        gcm.decrypt(&iv, b"other header", &cipher),
+        Err(AESError::AuthenticationFailed)
+    ));
+    assert!(matches!(
+        gcm.decrypt(&nonce("cafebabefacedbaddecaf889"), aad, &cipher),
+        Err(AESError::AuthenticationFailed)
+    ));
+    assert!(matches!(
+        gcm.decrypt(&iv, aad, &cipher[..15]),
+        Err(AESError::InvalidLength)
+    ));
+}
@@ -0,0 +1,101 @@
+use spectrum::cryptography::aes::{InitKey, AES};
+use spectrum::cryptography::hkdf::{HKDFError, HKDF};
+use spectrum::cryptography::sha::SHA;
+use spectrum::cryptography::sha3::{SHAKEType, SHAKE};
+use spectrum::cryptography::CryptographicAlgorithm;
+use spectrum::format::{hex_to_u8, u8_to_hex};
+
+// Test vectors from RFC 5869
+#[test]
+fn hkdf_sha256_case_1() {
+    let hkdf = HKDF::new(SHA::new());
+    let ikm = [0x0b; 22];
+    let salt: Vec<u8> = (0x00..=0x0c).collect();
+    let info: Vec<u8> = (0xf0..=0xf9).collect();
+
+    let prk = hkdf.extract(&salt, &ikm).unwrap();
+    assert_eq!(
+        u8_to_hex(prk.clone()),
+        "077709362c2e32df0ddc3f0dc47bba6390b6c73bb50f9c3122ec844ad7c2b3e5"
+    );
+    assert_eq!(
+        u8_to_hex(hkdf.expand(&prk, &info, 42).unwrap()),
+        "3cb25f25faacd57a90434f64d0362f2a2d2d0a90cf1a5a4c5db02d56ecc4c5bf34007208d5b887185865"
+    );
+}
+
+#[test]
+fn hkdf_sha256_case_2() {
+    let hkdf = HKDF::new(SHA::new());
+    let ikm: Vec<u8> = (0x00..=0x4f).collect();
+    let salt: Vec<u8> = (0x60..=0xaf).collect();
+    let info: Vec<u8> = (0xb0..=0xff).collect();
+
+    let prk = hkdf.extract(&salt, &ikm).unwrap();
+    assert_eq!(
+        u8_to_hex(prk.clone()),
+        "06a6b88c5853361a06104c9ceb35b45cef760014904671014a193f40c15fc244"
+    );
+    assert_eq!(
+        u8_to_hex(hkdf.expand(&prk, &info, 82).unwrap()),
+        "b11e398dc80327a1c8e7f78c596a49344f012eda2d4efad8a050cc4c19afa97c59045a99cac7827271cb41c65e590e09da3275600c2f09b8367793a9aca3db71cc30c58179ec3e87c14c01d5c1f3434f1d87"
+    );
+}
+
+#[test]
+fn hkdf_sha256_case_3() {
+    let hkdf = HKDF::new(SHA::new());
+    let ikm = [0x0b; 22];
+
+    let prk = hkdf.extract(&[], &ikm).unwrap();
+    assert_eq!(
+        u8_to_hex(prk),
+        "19ef24a32c717b167f33a91d6f648bdf96596776afdb6377ac434c1c293ccb04"
+    );
+    assert_eq!(
+        u8_to_hex(hkdf.derive(&[], &ikm, &[], 42).unwrap()),
+        "8da4e775a563c18f715f802a063c5a31b8a11f5c5ee1879ec3454e5f3c738d2d9d201395faa4b61a96c8"
+    );
+}
+
+#[test]
+fn hkdf_output_too_long() {
+    let hkdf = HKDF::new(SHA::new());
+    assert!(hkdf.expand(&[0; 32], &[], 255 * 32).is_ok());
+    assert!(matches!(
+        hkdf.expand(&[0; 32], &[], 255 * 32 + 1),
+        Err(HKDFError::OutputTooLong)
+    ));
+}
+
+#[test]
+fn hkdf_derive_init_key() {
+    let hkdf = HKDF::new(SHA::new());
+    let init_key = hkdf
+        .derive_init_key(b"salt", b"shared secret", b"aes key", 32)
+        .unwrap();
+
+    assert!(matches!(init_key, InitKey::AES256(_)));
+    assert_eq!(
+        init_key.as_bytes(),
+        hex_to_u8("2c1ecc95991012525c3741a0111d9f422d32a4f544acf1fe06265a1a36b0dc89")
+    );
+
+    let aes = AES::from_init_key(init_key);
+    let message = b"sixteen byte msg";
+    assert_eq!(aes.decrypt(&aes.encrypt(message)), message);
+
+    assert!(matches!(
+        hkdf.derive_init_key(b"salt", b"shared secret", b"aes key", 20),
+        Err(HKDFError::InvalidKeySize)
+    ));
+}
+
+#[test]
+fn hkdf_rejects_hash_unusable_by_hmac() {
+    let hkdf = HKDF::new(SHAKE::from_type(SHAKEType::SHAKE128, 200));
+    assert!(matches!(
+        hkdf.extract(b"salt", b"ikm"),
+        Err(HKDFError::InvalidHash)
+    ));
+}
@@ -0,0 +1,181 @@
+use spectrum::cryptography::hmac::{HMACError, HMAC};
+use spectrum::cryptography::sha::{SHAType, SHA};
+use spectrum::cryptography::sha3::{SHA3Type, SHAKEType, SHA3, SHAKE};
+use spectrum::format::u8_to_hex;
+
+// Test vectors from RFC 4231
+
+#[test]
+fn hmac_sha256_case_1() {
+    let key: Vec<u8> = vec![0x0b; 20];
+    let hmac = HMAC::new(SHA::from_type(SHAType::SHA256), &key).unwrap();
+    assert_eq!(
+        u8_to_hex(hmac.mac(b"Hi There")),
+        "b0344c61d8db38535ca8afceaf0bf12b881dc200c9833da726e9376c2e32cff7"
+    );
+}
+
+#[test]
+fn hmac_sha512_case_1() {
+    let key: Vec<u8> = vec![0x0b; 20];
+    let hmac = HMAC::new(SHA::from_type(SHAType::SHA512), &key).unwrap();
+    assert_eq!(
+        u8_to_hex(hmac.mac(b"Hi There")),
+        "87aa7cdea5ef619d4ff0b4241a1d6cb02379f4e2ce4ec2787ad0b30545e17cdedaa833b7d6b8a702038b274eaea3f4e4be9d914eeb61f1702e696c203a126854"
+    );
+}
+
+#[test]
+fn hmac_sha256_case_2() {
+    let key: Vec<u8> = b"Jefe".to_vec();
+    let hmac = HMAC::new(SHA::from_type(SHAType::SHA256), &key).unwrap();
+    assert_eq!(
+        u8_to_hex(hmac.mac(b"what do ya want for nothing?")),
+        "5bdcc146bf60754e6a042426089575c75a003f089d2739839dec58b964ec3843"
+    );
+}
+
+#[test]
+fn hmac_sha512_case_2() {
+    let key: Vec<u8> = b"Jefe".to_vec();
+    let hmac = HMAC::new(SHA::from_type(SHAType::SHA512), &key).unwrap();
+    assert_eq!(
+        u8_to_hex(hmac.mac(b"what do ya want for nothing?")),
+        "164b7a7bfcf819e2e395fbe73b56e0a387bd64222e831fd610270cd7ea2505549758bf75c05a994a6d034f65f8f0e6fdcaeab1a34d4a6b4b636e070a38bce737"
+    );
+}
+
+#[test]
+fn hmac_sha256_case_3() {
+    let key: Vec<u8> = vec![0xaa; 20];
+    let hmac = HMAC::new(SHA::from_type(SHAType::SHA256), &key).unwrap();
+    assert_eq!(
+        u8_to_hex(hmac.mac(&[0xdd; 50])),
+        "773ea91e36800e46854db8ebd09181a72959098b3ef8c122d9635514ced565fe"
+    );
+}
+
+#[test]
+fn hmac_sha512_case_3() {
+    let key: Vec<u8> = vec![0xaa; 20];
+    let hmac = HMAC::new(SHA::from_type(SHAType::SHA512), &key).unwrap();
+    assert_eq!(
+        u8_to_hex(hmac.mac(&[0xdd; 50])),
+        "fa73b0089d56a284efb0f0756c890be9b1b5dbdd8ee81a3655f83e33b2279d39bf3e848279a722c806b485a47e67c807b946a337bee8942674278859e13292fb"
+    );
+}
+
+#[test]
+fn hmac_sha256_case_4() {
+    let key: Vec<u8> = (1..=25).collect();
+    let hmac = HMAC::new(SHA::from_type(SHAType::SHA256), &key).unwrap();
+    assert_eq!(
+        u8_to_hex(hmac.mac(&[0xcd; 50])),
+        "82558a389a443c0ea4cc819899f2083a85f0faa3e578f8077a2e3ff46729665b"
+    );
+}
+
+#[test]
+fn hmac_sha512_case_4() {
+    let key: Vec<u8> = (1..=25).collect();
+    let hmac = HMAC::new(SHA::from_type(SHAType::SHA512), &key).unwrap();
+    assert_eq!(
+        u8_to_hex(hmac.mac(&[0xcd; 50])),
+        "b0ba465637458c6990e5a8c5f61d4af7e576d97ff94b872de76f8050361ee3dba91ca5c11aa25eb4d679275cc5788063a5f19741120c4f2de2adebeb10a298dd"
+    );
+}
+
+#[test]
+fn hmac_sha256_case_5() {
+    let key: Vec<u8> = vec![0x0c; 20];
+    let hmac = HMAC::new(SHA::from_type(SHAType::SHA256), &key).unwrap();
+    assert_eq!(
+        u8_to_hex(hmac.mac(b"Test With Truncation")[..16].to_vec()),
+        "a3b6167473100ee06e0c796c2955552b"
+    );
+}
+
+#[test]
+fn hmac_sha512_case_5() {
+    let key: Vec<u8> = vec![0x0c; 20];
+    let hmac = HMAC::new(SHA::from_type(SHAType::SHA512), &key).unwrap();
+    assert_eq!(
+        u8_to_hex(hmac.mac(b"Test With Truncation")[..16].to_vec()),
+        "415fad6271580a531d4179bc891d87a6"
+    );
+}
+
+#[test]
+fn hmac_sha256_case_6() {
+    let key: Vec<u8> = vec![0xaa; 131];
+    let hmac = HMAC::new(SHA::from_type(SHAType::SHA256), &key).unwrap();
+    assert_eq!(
+        u8_to_hex(hmac.mac(b"Test Using Larger Than Block-Size Key - Hash Key First")),
+        "60e431591ee0b67f0d8a26aacbf5b77f8e0bc6213728c5140546040f0ee37f54"
+    );
+}
+
+#[test]
+fn hmac_sha512_case_6() {
+    let key: Vec<u8> = vec![0xaa; 131];
+    let hmac = HMAC::new(SHA::from_type(SHAType::SHA512), &key).unwrap();
+    assert_eq!(
+        u8_to_hex(hmac.mac(b"Test Using Larger Than Block-Size Key - Hash Key First")),
+        "80b24263c7c1a3ebb71493c1dd7be8b49b46d1f41b4aeec1121b013783f8f3526b56d037e05f2598bd0fd2215d6a1e5295e64f73f63f0aec8b915a985d786598"
+    );
+}
+
+#[test]
+fn hmac_sha256_case_7() {
+    let key: Vec<u8> = vec![0xaa; 131];
+    let hmac = HMAC::new(SHA::from_type(SHAType::SHA256), &key).unwrap();
+    assert_eq!(
+        u8_to_hex(hmac.mac(b"This is a test using a larger than block-size key and a larger than block-size data. The key needs to be hashed before being used by the HMAC algorithm.")),
+        "9b09ffa71b942fcb27635fbcd5b0e944bfdc63644f0713938a7f51535c3a35e2"
+    );
+}
+
+#[test]
+fn hmac_sha512_case_7() {
+    let key: Vec<u8> = vec![0xaa; 131];
+    let hmac = HMAC::new(SHA::from_type(SHAType::SHA512), &key).unwrap();
+    assert_eq!(
+        u8_to_hex(hmac.mac(b"This is a test using a larger than block-size key and a larger than block-size data. The key needs to be hashed before being used by the HMAC algorithm.")),
+        "e37b6a775dc87dbaa4dfa9f96e5e3ffddebd71f8867289865df5a32d20cdc944b6022cac3c4982b10d5eeb55c3e4de15134676fb6de0446065c97440fa8c6a58"
+    );
+}
+
+#[test]
+fn hmac_sha3_256() {
+    let hmac = HMAC::new(SHA3::from_type(SHA3Type::SHA3_256), b"key").unwrap();
+    assert_eq!(
+        u8_to_hex(hmac.mac(b"The quick brown fox jumps over the lazy dog")),
+        "8c6e0683409427f8931711b10ca92a506eb1fafa48fadd66d76126f47ac2c333"
+    );
+}
+
+#[test]
+fn hmac_verify() {
+    let hmac = HMAC::new(SHA::new(), b"Jefe").unwrap();
+    let message = b"what do ya want for nothing?";
+    let mut tag = hmac.mac(message);
+    assert!(hmac.verify(message, &tag));
+    assert!(!hmac.verify(b"what do ya want for something?", &tag));
+    assert!(!hmac.verify(message, &tag[..31]));
+
+    tag[31] ^= 1;
+    assert!(!hmac.verify(message, &tag));
+}
+
+#[test]
+fn hmac_rejects_outputs_longer_than_a_block() {
+    assert!(matches!(
+        HMAC::new(SHAKE::from_type(SHAKEType::SHAKE128, 200), b"key"),
+        Err(HMACError::InvalidHash)
+    ));
+    assert!(matches!(
+        HMAC::new(SHAKE::from_type(SHAKEType::SHAKE128, 0), b"key"),
+        Err(HMACError::InvalidHash)
+    ));
+    assert!(HMAC::new(SHAKE::from_type(SHAKEType::SHAKE128, 168), b"key").is_ok());
+}
@@ -0,0 +1,192 @@
+use spectrum::cryptography::aes::{AESError, InitKey, KeyWrap, AES};
+use spectrum::format::{hex_to_u8, u8_to_hex};
+
+fn key_wrap(kek: &str) -> KeyWrap {
+    KeyWrap::new(AES::from_init_key(
+        InitKey::from_slice(&hex_to_u8(kek)).unwrap(),
+    ))
+}
+
+fn check(kek: &str, key: &str, expected: &str) {
+    let key_wrap = key_wrap(kek);
+    let wrapped = key_wrap.wrap(&InitKey::from_slice(&hex_to_u8(key)).unwrap());
+
+    assert_eq!(u8_to_hex(wrapped.clone()), expected);
+    assert_eq!(
+        u8_to_hex(key_wrap.unwrap(&wrapped).unwrap().as_bytes().to_vec()),
+        key
+    );
+}
+
+fn check_with_padding(kek: &str, key: &str, expected: &str) {
+    let key_wrap = key_wrap(kek);
+    let wrapped = key_wrap.wrap_with_padding(&hex_to_u8(key)).unwrap();
+
+    assert_eq!(u8_to_hex(wrapped.clone()), expected);
+    assert_eq!(
+        u8_to_hex(key_wrap.unwrap_with_padding(&wrapped).unwrap()),
+        key
+    );
+}
+
+// Test vectors from RFC 3394 section 4
+
+#[test]
+fn key_wrap_rfc3394_4_1() {
+    check(
+        "000102030405060708090a0b0c0d0e0f",
+        "00112233445566778899aabbccddeeff",
+        "1fa68b0a8112b447aef34bd8fb5a7b829d3e862371d2cfe5",
+    );
+}
+
+#[test]
+fn key_wrap_rfc3394_4_2() {
+    check(
+        "000102030405060708090a0b0c0d0e0f1011121314151617",
+        "00112233445566778899aabbccddeeff",
+        "96778b25ae6ca435f92b5b97c050aed2468ab8a17ad84e5d",
+    );
+}
+
+#[test]
+fn key_wrap_rfc3394_4_3() {
+    check(
+        "000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f",
+        "00112233445566778899aabbccddeeff",
+        "64e8c3f9ce0f5ba263e9777905818a2a93c8191e7d6e8ae7",
+    );
+}
+
+#[test]
+fn key_wrap_rfc3394_4_4() {
+    check(
+        "000102030405060708090a0b0c0d0e0f1011121314151617",
+        "00112233445566778899aabbccddeeff0001020304050607",
+        "031d33264e15d33268f24ec260743edce1c6c7ddee725a936ba814915c6762d2",
+    );
+}
+
+#[test]
+fn key_wrap_rfc3394_4_5() {
+    check(
+        "000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f",
+        "00112233445566778899aabbccddeeff0001020304050607",
+        "a8f9bc1612c68b3ff6e6f4fbe30e71e4769c8b80a32cb8958cd5d17d6b254da1",
+    );
+}
+
+#[test]
+fn key_wrap_rfc3394_4_6() {
+    check(
+        "000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f",
+        "00112233445566778899aabbccddeeff000102030405060708090a0b0c0d0e0f",
+        "28c9f404c4b810f4cbccb35cfb87f8263f5786e2d80ed326cbc7f0e71a99f43bfb988b9b7a02dd21",
+    );
+}
+
+// Test vectors from RFC 5649 section 6
+
+#[test]
+fn key_wrap_with_padding_rfc5649_20_octets() {
+    check_with_padding(
+        "5840df6e29b02af1ab493b705bf16ea1ae8338f4dcc176a8",
+        "c37b7e6492584340bed12207808941155068f738",
+        "138bdeaa9b8fa7fc61f97742e72248ee5ae6ae5360d1ae6a5f54f373fa543b6a",
+    );
+}
+
+#[test]
+fn key_wrap_with_padding_rfc5649_7_octets() {
+    check_with_padding(
+        "5840df6e29b02af1ab493b705bf16ea1ae8338f4dcc176a8",
+        "466f7250617369",
+        "afbeb0f07dfbf5419200f2ccb50bb24f",
+    );
+}
+
+#[test]
+fn key_wrap_with_padding_lengths() {
+    let key_wrap = key_wrap("000102030405060708090a0b0c0d0e0f");
+
+    for length in 1..=40usize {
+        let key: Vec<u8> = (0..length).map(|i| i as u8).collect();
+        let wrapped = key_wrap.wrap_with_padding(&key).unwrap();
+
+        assert_eq!(wrapped.len(), 8 + length.next_multiple_of(8).max(8));
+        assert_eq!(key_wrap.unwrap_with_padding(&wrapped).unwrap(), key);
+    }
+
+    assert_eq!(
+        u8_to_hex(
+            key_wrap
+                .wrap_with_padding(&hex_to_u8("01010101010101010101010101010101"))
+                .unwrap()
+        ),
+        "6937f24eab55398d2ee1431e4341585e3e4792763e187773"
+    );
+
+    assert_eq!(
+        u8_to_hex(
+            key_wrap
+                .wrap_with_padding(&hex_to_u8(
+                    "0202020202020202020202020202020202020202020202020202020202020202"
+                ))
+                .unwrap()
+        ),
+        "86ded53b24b66fe921877c505a334a5cd49f3fc9f6f4e1350d6e38141ac9053fda6baf9d0fda4bd0"
+    );
+}
+
+#[test]
+fn key_wrap_integrity_check() {
+    let key_wrap = key_wrap("000102030405060708090a0b0c0d0e0f");
+    let wrapped = key_wrap.wrap(&InitKey::AES256([7; 32]));
+
+    for i in 0..wrapped.len() {
+        let mut tampered = wrapped.clone();
+        tampered[i] ^= 0x01;
+        assert!(matches!(
+            key_wrap.unwrap(&tampered),
+            Err(AESError::AuthenticationFailed)
+        ));
+    }
+
+    let wrapped = key_wrap.wrap_with_padding(b"secret key").unwrap();
+    for i in 0..wrapped.len() {
+        let mut tampered = wrapped.clone();
+        tampered[i] ^= 0x01;
+        assert!(matches!(
+            key_wrap.unwrap_with_padding(&tampered),
+            Err(AESError::AuthenticationFailed)
+        ));
+    }
+
+    // A key wrapped without padding is rejected by the padded variant and vice versa
+    let wrapped = key_wrap.wrap(&InitKey::AES128([7; 16]));
+    assert!(key_wrap.unwrap_with_padding(&wrapped).is_err());
+    let wrapped = key_wrap.wrap_with_padding(&[7; 16]).unwrap();
+    assert!(key_wrap.unwrap(&wrapped).is_err());
+}
+
+#[test]
+fn key_wrap_invalid_lengths() {
+    let key_wrap = key_wrap("000102030405060708090a0b0c0d0e0f");
+
+    assert!(matches!(
+        key_wrap.unwrap(&[0; 16]),
+        Err(AESError::InvalidLength)
+    ));
+    assert!(matches!(
+        key_wrap.unwrap(&[0; 25]),
+        Err(AESError::InvalidLength)
+    ));
+    assert!(matches!(
+        key_wrap.wrap_with_padding(&[]),
+        Err(AESError::InvalidLength)
+    ));
+    assert!(matches!(
+        key_wrap.unwrap_with_padding(&[0; 8]),
+        Err(AESError::InvalidLength)
+    ));
+}
@@ -0,0 +1,114 @@
+use rand::Rng;
+use spectrum::format::{hex_to_u8, u8_to_hex};
+use spectrum::math::{exponential_modulus, multiply_modulus, BigUint, Montgomery};
+
+fn big(hex: &str) -> BigUint {
+    BigUint::from_be_bytes(&hex_to_u8(hex))
+}
+
+fn hex(number: &BigUint) -> String {
+    u8_to_hex(number.to_be_bytes())
+}
+
+// Random operands, results computed with Python's pow
+const MODULUS: &str = "e4a75371f4eb68202f3835fa422737a355b650d19cc14e74539129deb2c9612d0fa8f64290f9e229f3427d74f610ae8cfba8a80ec621ba26ba983107f0200a7787e54b499533f2491c8fb400d98d0c6c2e37499e30ac8b566be8a4d74f88cda74393b3a296ed215605752205e1a14b1b93bfbb8b0c6695ffe232a3dab54705e46e15336bec816103bbae4d5faf6d39398d4fc201ee9d4b092ddbd20899e47610f9e20aa751c7987e0cb69ab7f5a0d02e44ee9bd73b53690a14646e57e3b99c58cae64fa6587c2e15e0ed9827a6c38ad2bd55fcad1edf1f1eb3b3406c2f2b3f2c72775666ffa642399cf342ca060bb5253e1c26d323ef323ee848f808f54d35bf";
+const BASE: &str = "0326b1f3c08c03d757b49dedf3fc970edcf376671e1ba7c7fe874f6e87c7ad193bf4905b0f22647198ae0cff1995d8172f7e96168e57646d664ecb873ac894e6328b8bac594e8dc1ee8e466ef802a486f2515a8b09f22c8fb18f9d987b806393561ca94c1d10fb0673b6899524df2865afc9a55faff222903295ed4eaba0352f52d9feea9d5b1aeca9c6d0a93748e465b3826868d132fae1da5ffc846e893e48c63e18819b8bb44067da30ecd21a066b5ce807084279a7e6b6ab9e8825217357a8b8930c9376906e91a2a32f5e04e2b11f86fdaae15479454649d7d3420229478f02262468c08f83ea22e7a69fcff2f4c0c5f7f462ea71dee6ea94c7f6cc42e69660c34ffe307a";
+const EXPONENT: &str = "1c5bbe3bc8bfa7bbf46a74c75a90e2a8b6ce5b32a4ad317d10dc75a4ba2d517279e66f117918fe96dbbc53f0be61b28daf11119efdc4cef148c1a13695a49eca22b4ae128621f28bb52789a37f9fc5181ebc9c78d8b666690b10704b75e84e10964abe0debc7a52625c70204d3fe693b1f3ae7096926b10731ef0e2b0d55e82319db0e85b1934e02a7d3f2073395fe6bbd31e1d1a5a6e0c8c7623186cbc0946a2274bc0889136a4f1014e043097735a2718ec74230f66ff3a129b8c85b71fe54e8e6fb63540aaf632dc989fa596128ba605248788befaee707b817802be09a133185844be53f83ae757fb2dc18b88a0201bae140669197a93d1e542d8312011a";
+const POWER: &str = "803ae483c1c34c9d4d45b74abc524f5551b6ad9f2dfbd620bfea7d5ad3dc5e5556690dd3e140fd25759432687a3ee49833e25ca3133c616682086dbb8a24cd2f2c2a22f48822ed4b03e840db5f9f3fdff812797d90d6d88578fa17cf083363ca4aa1273f87c69528363919dc2fed5f11bcf9f0edc1b4e1efb82ad047e4e5a5151d3e22e6b7da6cf2245b0d51696f03daf7cf06c2bb82f2bc98a2447ed7c08c843268e78420a6c11dac153373d79235e7f8d27d3c8d49578f08e48c906714249f39208f2548834a9e7ca4707acefd0c03270917df90b6011f54aef67ff715f789e402acde96164b1c28597f7c8e7fb8aaeb2ef084758e1ee458540292b05e6dc3";
+const F4_POWER: &str = "c4d3f975779735a2df3c0f15cd73e7d7f338ec3cee82100e44e64dd4153ec60c13f7ecc3ada223bef9177e8c77c8de0b0e6b4f989a555c77c9b1c04f90c2d61680c9147e0b6804aa001b38b04dd1af2b1e53c9bb4a15b33e77135708dc79c1c3dceb6f0249f1af2abb4e5fbf762bdf974c93a35265b87fec1c8afe86536bcf0c074f8cc8899a9a27999bcddbf0c439c9964303cde6aa419652fd0ec49fb74dbc032f3a93989a642cac3bdfdeb5bef9ec8b592a108291198304aa9655d17c3b40fcc99c6e7600fda35c64adee5e452117f84517a2ea0cd3281bf5b92838df1af14988ca0a54897c7972a496216f8da5355e6d30b3e3c1d2d5d1131a203759169e";
+const EVEN_MODULUS: &str = "01119f20c66ec50953fc7bf8963c76769b5b9041ccb45dea8461930e29ef2c3d822722aa66156f9b165ac9878089b9ef19665ef736de699471319be74162580ddf420e572857bef5e831cad50cae5fb03620cb127f44e740e8b353c60900011cec5fa851460950214c3d2cbc2de2ad38fe848be6f937813ba39d8caeeda3bc3212";
+const EVEN_POWER: &str = "2d29e997919cd89ba05bd0c2217afa1f240243f46354610d81d9fe51828eeb5fc9f54678d1ddd2005cd80af7908d03aaa8c4115dfd0212cf95264ea248cf796790bfc592510f357dc0e6c2471d73e3b468a0d09307de9582a0916828a444ccdef5076bce60fa0887871328b6e84c7118ffec6603935d161d7b97346bd3a1eebe";
+
+#[test]
+fn exponential_modulus_u128() {
+    assert_eq!(exponential_modulus(4, 13, 497), 445);
+    assert_eq!(exponential_modulus(5, 0, 7), 1);
+    assert_eq!(exponential_modulus(5, 3, 1), 0);
+    assert_eq!(
+        exponential_modulus(123456789, 987654321, (1 << 127) - 1),
+        54332918125842946475806989909357123968
+    );
+    assert_eq!(
+        exponential_modulus(u128::MAX - 5, u128::MAX - 7, u128::MAX - 158),
+        206273873869908053169165936592638108153
+    );
+    assert_eq!(
+        exponential_modulus(3, 1 << 100, 340282366920938463463374607431768211297),
+        183035793648830144139372177945312118097
+    );
+}
+
+#[test]
+fn multiply_modulus_u128() {
+    let mut rng = rand::thread_rng();
+
+    for _ in 0..1000 {
+        let (a, b, n) = (
+            rng.gen::<u64>() as u128,
+            rng.gen::<u64>() as u128,
+            rng.gen::<u64>() as u128 + 1,
+        );
+        assert_eq!(multiply_modulus(a, b, n), a * b % n);
+    }
+
+    assert_eq!(multiply_modulus(u128::MAX - 1, u128::MAX - 1, u128::MAX), 1);
+}
+
+#[test]
+fn modpow_montgomery() {
+    let (base, exponent, modulus) = (big(BASE), big(EXPONENT), big(MODULUS));
+
+    assert_eq!(hex(&base.modpow(&exponent, &modulus)), POWER);
+    assert_eq!(
+        hex(&base.modpow(&BigUint::from_u128(65537), &modulus)),
+        F4_POWER
+    );
+    assert_eq!(base.modpow(&BigUint::zero(), &modulus), BigUint::one());
+}
+
+#[test]
+fn modpow_constant_time() {
+    let (base, exponent, modulus) = (big(BASE), big(EXPONENT), big(MODULUS));
+
+    assert_eq!(hex(&base.modpow_constant_time(&exponent, &modulus)), POWER);
+    assert_eq!(
+        hex(&base.modpow_constant_time(&BigUint::from_u128(65537), &modulus)),
+        F4_POWER
+    );
+    assert_eq!(
+        base.modpow_constant_time(&BigUint::zero(), &modulus),
+        BigUint::one()
+    );
+}
+
+#[test]
+fn modpow_even_modulus() {
+    let (base, exponent) = (big(BASE), big(EXPONENT));
+
+    assert_eq!(hex(&base.modpow(&exponent, &big(EVEN_MODULUS))), EVEN_POWER);
+    assert_eq!(base.modpow(&exponent, &BigUint::one()), BigUint::zero());
+    assert!(Montgomery::new(&big(EVEN_MODULUS)).is_none());
+    assert!(Montgomery::new(&BigUint::one()).is_none());
+}
+
+#[test]
+fn modpow_matches_u128() {
+    let mut rng = rand::thread_rng();
+
+    for _ in 0..200 {
+        let (base, exponent, modulus): (u128, u128, u128) =
+            (rng.gen(), rng.gen(), rng.gen::<u128>() | 1);
+        let expected = Some(exponential_modulus(base, exponent, modulus));
+        let (base, exponent, modulus) = (
+            BigUint::from_u128(base),
+            BigUint::from_u128(exponent),
+            BigUint::from_u128(modulus),
+        );
+
+        assert_eq!(base.modpow(&exponent, &modulus).to_u128(), expected);
+        assert_eq!(
+            base.modpow_constant_time(&exponent, &modulus).to_u128(),
+            expected
+        );
+    }
+}
@@ -0,0 +1,102 @@
+use spectrum::cryptography::rsa::{RSAError, OAEP, RSA};
+use spectrum::cryptography::sha::{SHAType, SHA};
+use spectrum::cryptography::sha3::SHA3;
+use spectrum::format::hex_to_u8;
+use spectrum::math::BigUint;
+
+// 2048-bit key and ciphertexts generated with pyca/cryptography
+const N: &str = "ddb9e3b008d99f338288bf21c5594d948a236da5fde0916ff0ccc03b863c44a6a6165e3a0e6d313a941146aeea1363ed4519400762a9fe9541a218546d47b42a2e71de70960b29c32c8b3731275325c52faac2318d15664944cfc7496fa5b4b7ca01f98301c3085fe44bd6149e7af45a4ab7cff3e788d045614ccec7d52dfc9de1e52f3d9b3017f90cb230a8df5b4340ccf6e53ccce32bdb90e6a9224be6a1ca85cc651f1987fe351277da34c3d172fe6648f69ad5af9971c4788ef1b4d469f486ee6c00c497df45d4b92d14c169867328129b416d18c6be91f413cdcb3aaa7474ea3f7df204dd0c04b55b226b2be774cd484a9a52cd0b3aafaa9f4a1cff5071";
+const D: &str = "049237259b4f1a4342790d80b59bfd57973ffafc19b7722425c9a0da714e67876c83e84a27df3c2d361d8390aa5a7f3c46690a7b047f96f42e8bd816d08072904e90122b0dcad9dff05887e9236389532f04051826ad96e3e28f6a8f42e61e8b4df6bbb545d966a02c799c46a54c49629da8fbb86e70f18346a9e1d6f89807bdd3361506fe95def03dfa7c2ebab9a0a58cdfc6f5e703089fed3afa80e4957d23ffee6bc48b581588b30afe95bf5ec8f158569ca6fd6feee5cb6e6336cf77c37261e2b387fb056469725d2dcaf576a06a6811d0c5e1792fad54c5f89a4497ae147f621bae49d6647726dbe17015a1b66b1dce09eab2ff05e1ab1077962b765941";
+const SHA256_CIPHER: &str = "70faa223d1cd979e2bbb99dde29836e9c7b73f7482cc5fe5c43000f9a27e80fc84e59aa835e10703b87c78f56e9d821f0e4ca3f0d7182cd96d47d8137b673cdb90ce92a8450c8b0eeaf0bf224f30714406299931f4354c699d9c4f5f69f33fadeb6e80bf7f4b97d249761fde90fcbfd729f8f0cf08f1479574637c6f6b0971eca2e511845c1a0dddb3ed48c0a57d1a2e02d4cfa8b0a3f68381ac5293b2c9769e7027d91d8974e3d495d56d70dd78e6159c2bfd1594b18fe423fb01db687d24ffec6abf6e38faf4b1bda09b1f5e15dba20255496a3e36e68f62b3ba17915a045b44027dedc828f8f2d0b6ecece45b6dd86341f6c7ed2b4f33ce307bb0f3a2b4cb";
+const SHA512_LABEL_CIPHER: &str = "71e3da15e69267e648e6ab7ce8067d61380a1427e026e4c7301fdbceea7c63f42a14cb2363af6fbe20f27583607b4fa7c5a015a8224f3224c716cf2e94997084445d6a17cb9f1b72e405c044596b3be064be948e98318f3ada8ad7a8799971d9ee1add8631343fb46dcb44320cbd05d492c6e3be1c03543aea26277248cc8d9e6ef3c44024d5537a7b64119f308d74d106382765978a465ba67687315b706f024da584d2ae43432df9b2fddcc54fc42b993bf02870d3c1b42c18fc527f4e94943aae7ddce4b863961a5315e827827f3995c59e9157fa921f2ac90d05c4855d7fe59c93bb68f30874d57d57b42fac3b3713ba25fdfde29e49c94c9ab59e7b79b8";
+const SHA256_EMPTY_CIPHER: &str = "4c81d9a7594aa2950edb3193abc847fc8c642d7ea42d48e1dd8c4dfdff7b71bffdff0fbb9d8d965271f1e4b36a74d5f65c0feb445a857ce99faab1894a6c12a3e1654a6aee6e2b572c37296d9586f47c9f87419518f5dfca4e9e9f0fda7a7cb423b33d47afb5660d8f4854bcae7c19c3eb88ddb23e44d57914c80461ff76f533de29c789a67174495153847c6fd6d86532f465afa5fbc72a58360d9b4c0c989c6c1f249679a8bb67b601ed02ec1ec952eea4dd127111b23eedac16343295502b22c6f6ead69b81dbca428c48c77169705efe103986d211c15c6c48e28cc9309f1db70378ee1a03c538f96df9b2c14516d31244b55e3515201c28fd6feaaffa89";
+
+fn key() -> RSA {
+    RSA::from_components(
+        BigUint::from_be_bytes(&hex_to_u8(N)),
+        BigUint::from_u128(65537),
+        BigUint::from_be_bytes(&hex_to_u8(D)),
+    )
+    .unwrap()
+}
+
+#[test]
+fn oaep_decrypts_reference_ciphertexts() {
+    let oaep = OAEP::new(key(), SHA::new(), b"").unwrap();
+    assert_eq!(
+        oaep.decrypt(&hex_to_u8(SHA256_CIPHER)).unwrap(),
+        b"OAEP with SHA-256"
+    );
+    assert_eq!(oaep.decrypt(&hex_to_u8(SHA256_EMPTY_CIPHER)).unwrap(), b"");
+
+    let oaep = OAEP::new(key(), SHA::from_type(SHAType::SHA512), b"label").unwrap();
+    assert_eq!(
+        oaep.decrypt(&hex_to_u8(SHA512_LABEL_CIPHER)).unwrap(),
+        b"OAEP with SHA-512 and a label"
+    );
+}
+
+#[test]
+fn oaep_round_trip() {
+    let oaep = OAEP::new(key(), SHA3::new(), b"context").unwrap();
+    let message = vec![0x42; oaep.max_message_length()];
+
+    let cipher = oaep.encrypt(&message).unwrap();
+    assert_eq!(cipher.len(), 256);
+    assert_eq!(oaep.decrypt(&cipher).unwrap(), message);
+
+    // Encryption is randomised
+    assert_ne!(oaep.encrypt(&message).unwrap(), cipher);
+}
+
+#[test]
+fn oaep_rejects_long_messages() {
+    let oaep = OAEP::new(key(), SHA::new(), b"").unwrap();
+
+    assert_eq!(oaep.max_message_length(), 256 - 2 * 32 - 2);
+    assert!(matches!(
+        oaep.encrypt(&[0; 191]),
+        Err(RSAError::MessageTooLong)
+    ));
+}
+
+#[test]
+fn oaep_rejects_wrong_label() {
+    let oaep = OAEP::new(key(), SHA::new(), b"other label").unwrap();
+
+    assert!(matches!(
+        oaep.decrypt(&hex_to_u8(SHA256_CIPHER)),
+        Err(RSAError::DecryptionError)
+    ));
+}
+
+#[test]
+fn oaep_rejects_malformed_ciphertexts() {
+    let oaep = OAEP::new(key(), SHA::new(), b"").unwrap();
+    let mut cipher = hex_to_u8(SHA256_CIPHER);
+
+    assert!(matches!(
+        oaep.decrypt(&cipher[1..]),
+        Err(RSAError::DecryptionError)
+    ));
+    assert!(matches!(
+        oaep.decrypt(&[0xff; 256]),
+        Err(RSAError::DecryptionError)
+    ));
+
+    cipher[100] ^= 1;
+    assert!(matches!(
+        oaep.decrypt(&cipher),
+        Err(RSAError::DecryptionError)
+    ));
+}
+
+#[test]
+fn oaep_rejects_small_keys() {
+    let rsa = RSA::generate(512).unwrap();
+
+    assert!(matches!(
+        OAEP::new(rsa, SHA::from_type(SHAType::SHA512), b""),
+        Err(RSAError::InvalidKeySize)
+    ));
+}
@@ -0,0 +1,114 @@
+use spectrum::cryptography::aes::InitKey;
+use spectrum::cryptography::pbkdf2::{PBKDF2Error, PBKDF2};
+use spectrum::cryptography::sha::SHA;
+use spectrum::cryptography::sha3::{SHAKEType, SHAKE};
+use spectrum::format::{hex_to_u8, u8_to_hex};
+
+fn pbkdf2(password: &[u8], salt: &[u8], iterations: u32, length: usize) -> String {
+    u8_to_hex(
+        PBKDF2::new(SHA::new(), iterations)
+            .derive(password, salt, length)
+            .unwrap(),
+    )
+}
+
+// Test vectors from RFC 7914 section 11
+#[test]
+fn pbkdf2_sha256_rfc7914_one_iteration() {
+    assert_eq!(
+        pbkdf2(b"passwd", b"salt", 1, 64),
+        "55ac046e56e3089fec1691c22544b605f94185216dde0465e68b9d57c20dacbc49ca9cccf179b645991664b39d77ef317c71b845b1e30bd509112041d3a19783"
+    );
+}
+
+#[test]
+fn pbkdf2_sha256_rfc7914_80000_iterations() {
+    assert_eq!(
+        pbkdf2(b"Password", b"NaCl", 80000, 64),
+        "4ddcd8f60b98be21830cee5ef22701f9641a4418d04c0414aeff08876b34ab56a1d425a1225833549adb841b51c9b3176a272bdebba1d078478f62b397f33c8d"
+    );
+}
+
+// RFC 6070 inputs with HMAC-SHA-256
+#[test]
+fn pbkdf2_sha256_rfc6070_inputs() {
+    assert_eq!(
+        pbkdf2(b"password", b"salt", 1, 32),
+        "120fb6cffcf8b32c43e7225256c4f837a86548c92ccc35480805987cb70be17b"
+    );
+    assert_eq!(
+        pbkdf2(b"password", b"salt", 2, 32),
+        "ae4d0c95af6b46d32d0adff928f06dd02a303f8ef3c251dfd6e2d85a95474c43"
+    );
+    assert_eq!(
+        pbkdf2(b"password", b"salt", 4096, 32),
+        "c5e478d59288c841aa530db6845c4c8d962893a001ce4e11a4963873aa98134a"
+    );
+    assert_eq!(
+        pbkdf2(
+            b"passwordPASSWORDpassword",
+            b"saltSALTsaltSALTsaltSALTsaltSALTsalt",
+            4096,
+            40
+        ),
+        "348c89dbcbd32b2f32d814b8116e84cf2b17347ebc1800181c4e2a1fb8dd53e1c635518c7dac47e9"
+    );
+    assert_eq!(
+        pbkdf2(b"pass\0word", b"sa\0lt", 4096, 16),
+        "89b69d0516f829893c696226650a8687"
+    );
+}
+
+#[test]
+fn pbkdf2_derive_init_key() {
+    let pbkdf2 = PBKDF2::new(SHA::new(), 1000);
+
+    let init_key = pbkdf2
+        .derive_init_key(b"correct horse battery staple", b"spectrum", 16)
+        .unwrap();
+    assert!(matches!(init_key, InitKey::AES128(_)));
+    assert_eq!(
+        init_key.as_bytes(),
+        hex_to_u8("161828d1fcafd761516882b873b0c2c2")
+    );
+
+    let init_key = pbkdf2
+        .derive_init_key(b"correct horse battery staple", b"spectrum", 24)
+        .unwrap();
+    assert!(matches!(init_key, InitKey::AES192(_)));
+    assert_eq!(
+        init_key.as_bytes(),
+        hex_to_u8("161828d1fcafd761516882b873b0c2c2d2e08f27b2a53848")
+    );
+
+    assert!(matches!(
+        pbkdf2.derive_init_key(b"password", b"salt", 8),
+        Err(PBKDF2Error::InvalidKeySize)
+    ));
+}
+
+#[test]
+fn pbkdf2_zero_iterations() {
+    assert!(matches!(
+        PBKDF2::new(SHA::new(), 0).derive(b"password", b"salt", 32),
+        Err(PBKDF2Error::InvalidIterationCount)
+    ));
+}
+
+#[test]
+fn pbkdf2_rejects_empty_hash_output() {
+    assert!(matches!(
+        PBKDF2::new(SHAKE::from_type(SHAKEType::SHAKE128, 0), 1).derive(b"password", b"salt", 32),
+        Err(PBKDF2Error::InvalidHash)
+    ));
+}
+
+// RFC 8018 allows at most 2^32 - 1 blocks of output
+#[test]
+fn pbkdf2_rejects_output_past_block_limit() {
+    let pbkdf2 = PBKDF2::new(SHAKE::from_type(SHAKEType::SHAKE128, 1), 1);
+    assert!(matches!(
+        pbkdf2.derive(b"password", b"salt", u32::MAX as usize + 1),
+        Err(PBKDF2Error::OutputTooLong)
+    ));
+}
@@ -0,0 +1,106 @@
+use spectrum::cryptography::rsa::{PKCS1v15, RSAError, RSA};
+use spectrum::format::{hex_to_u8, u8_to_hex};
+use spectrum::math::BigUint;
+
+// Same 2048-bit key as the OAEP tests. Ciphertexts and the decryptions, including
+// the synthetic messages for bad padding, come from pyca/cryptography on OpenSSL 3.5
+const N: &str = "ddb9e3b008d99f338288bf21c5594d948a236da5fde0916ff0ccc03b863c44a6a6165e3a0e6d313a941146aeea1363ed4519400762a9fe9541a218546d47b42a2e71de70960b29c32c8b3731275325c52faac2318d15664944cfc7496fa5b4b7ca01f98301c3085fe44bd6149e7af45a4ab7cff3e788d045614ccec7d52dfc9de1e52f3d9b3017f90cb230a8df5b4340ccf6e53ccce32bdb90e6a9224be6a1ca85cc651f1987fe351277da34c3d172fe6648f69ad5af9971c4788ef1b4d469f486ee6c00c497df45d4b92d14c169867328129b416d18c6be91f413cdcb3aaa7474ea3f7df204dd0c04b55b226b2be774cd484a9a52cd0b3aafaa9f4a1cff5071";
+const D: &str = "049237259b4f1a4342790d80b59bfd57973ffafc19b7722425c9a0da714e67876c83e84a27df3c2d361d8390aa5a7f3c46690a7b047f96f42e8bd816d08072904e90122b0dcad9dff05887e9236389532f04051826ad96e3e28f6a8f42e61e8b4df6bbb545d966a02c799c46a54c49629da8fbb86e70f18346a9e1d6f89807bdd3361506fe95def03dfa7c2ebab9a0a58cdfc6f5e703089fed3afa80e4957d23ffee6bc48b581588b30afe95bf5ec8f158569ca6fd6feee5cb6e6336cf77c37261e2b387fb056469725d2dcaf576a06a6811d0c5e1792fad54c5f89a4497ae147f621bae49d6647726dbe17015a1b66b1dce09eab2ff05e1ab1077962b765941";
+
+const VALID_CIPHER: &str = "d4c1c7eceb9f4df0cd4fdebd902a543b3aea4850410327937ec232c48733fb62447c791056ad44e2ee3477cc31ad47eecdaa5183db116a8e99cba9d490a1870a2883773b854a4c1e8ebbedad1514c8ad415368a679e3b08bc314a3010fa4bdf7d19ea33ca0b5bb92c9a636a4d0bb55f320f9376d7d01a8851c0ac44a318f5a3fe1bcc538f95701b8d61b0d3e88ef1f5bcdbd3b7748639ef609383afb401e2046fd5c9ff50c6b1a92ccfd18b166ca084a4dad54ae8a18c6e639f744266a27cd358a7fcbd6781715f1acf9aaf8f52547e64ec1313ba195da1e8b1d9ecb57b69905498a4f13b91637fea7b4eff53b2197acac5f8176a1c6efbba3f1e9aa05d4204e";
+const VALID_MESSAGE: &str = "504b43532023312076312e35";
+const WRONG_TYPE_CIPHER: &str = "8015dd4285a1d1750fc1c192130ec42d29e71c88052ea11f57df2d7ce1c191014be8b6ef8705e488ff52f49e72f3ffe260e603be964c3e45e3140c9157e9eed743d6a0dbaea0687eb98aa96d1c55b89179414239f2564d600f7f3d84930c6e957d480cd9199d008913cd738888e0c59f723c7d1350ffbfb60a89b28b51ca84d41b5a98e8e855f9ce0e81569be7d9e4bea00b74a4ccb24218d93470129af264c7db3cacd0c2d29640d4d0f44f60a034a7cd305359ec359a069be00d62a49234b2bb60d7c89fd1b29ac793b2df109d676e0698c34a632eeab658a96a958268eae2a9859ab7b1374b655a73e2bd09b377593bec29fac81b33a3ff9034c7b61c6333";
+const WRONG_TYPE_MESSAGE: &str = "15753feea85e8313ea1bd416d870ca52736d0e";
+const SHORT_PADDING_CIPHER: &str = "3db518238d951ecc540eaced623fbc3ee17821f4b8619c3a5f2d7dd5bef52fb8021a98f8d8d084c37ef50cdd93c3290c5fa3e2cb64c00f59e516fa8d41bf53bb86b9f17c14471d82cf8241e687355ce48c26a3b15e00f1c9b467ed431a2b3dce2d2cdcd5d41262e4cb31cdf1b6e04beb76941b4c487030b544cc46be0701e35e0aff88803d8c307e1c4108bace51a969c3b908382cc2bad4aff49e34e41bef5d1f15445eb071a5f250d9964e9489dbdf99bc83d4290eac92ab9d7ab0624fd2055d81a5fcabf18a1b2dea8d827a08e8750cb32b1279b782c97d733cdabc580c94cafce0072fa829a76809cf5c4f5119b27bef8935c503f75ca84f7b013226032b";
+const SHORT_PADDING_MESSAGE: &str = "edb2326fd12753d7e3736cf8b4eff1ce7772d6b959d2307f2081afc1ed179f7e3380b7d3bbecde99f6ad05dc368e92737e6daa144456c4b136b3d7ec52e4c0b719d60a1f1f337a21bed475e70cb04ee5fee1dc9d612d146aca45dc3542d60470dced6f4a28dcefb3e4cc854858403335a56a80568160040e3131adf94eef87e1711b7fc20df09ab4ec98aa2d8cbce5f8dedde1c2d13312e009b6075404f6af7cc33c654c29e00f90b60356b76ce971e6ab3d3ba17046a51271693d7b9e5a7f828ab5741aee1d47465ccf982a85d162a84169c6100c45247d54886ae74e49d5ed3944b8d5bde3";
+const NO_SEPARATOR_CIPHER: &str = "41839c5cfc64bec27e6fe5ac0a2194b43b190ed1ddda90b54ac8b568de6bddcb74d8b9aaffb7fa4113989ac8d0144b2c974692b8ae51a8430753821a724684c811711fd602763c7ad6e3d43ac46c276adff2210c80d45887c237e434f78a42698fd558f861bdb84678d81d8d7d9b03242ab5fc7f75a2c23201169db7fb10186248f5f3162af97c5dee6281fdd42fbe94976f00d91e6dbc9372f780c8750ebfc181cb172dd10f26527a988f4b58b86a6aeb586ab5636413922941af319cd1653f4ce626731f0b42c97cfeb8770ab174b54175af2ff2b3e2c2cbd9f2b81a938ee92658a29b2b54874d0cf97f2a29251548c7f0cea1a7ae058cc1a940a7a8e2a289";
+const NO_SEPARATOR_MESSAGE: &str = "85c2a80be637c04df3bce446924abb96a529232168e22be63f167b942cfbf1b07bb761133c62a918f54db766bfba32925be5a4506d9b95b3f740cc476fe4361441bb126c82118eb8e5f096befe102d5711cd0ca79b24dd30d0837aeb9fb2721a1030ba9338fb9b0c8b06f6c42df534508db865b8d648d2c1930c5b7a52347cec06ce20b3ccd5d823c962194963605e379f6376d3d6cd81a960bcf229991839262838e465b89cb117c5ecd7c508dce83b180565dc9019d205e502acb44bd2566cc5425a2045b5337089add4c0";
+const EIGHT_BYTE_PADDING_CIPHER: &str = "692b5a5a0704a131e172b4f90b0429879a3354c84749348f7f8a3e791055553e277e18a46fdb3f37b5410308747accad980ba05e6908a3bd8b9c5807e7b9ed9c832e11df2e829408aa6d9a0b6683c1a4a253251e24a67f9e4b733fb3876912e367997829ef433a57bd197dfd813176ba1593f0445b7ecf6dae1cafc5eb6a1f2b0c537d6c73a52445c18a83e29704eb03ba5ae59b15b8ee641294d02c2bcc438e1dc64fafea2a13b253510cbb7709e6094c553182f5a8e5eec1197469143ba95ff03159b6b4061e5aebc1f8ac9c1851e906e813b145cecce2eb74963099552d8e2e6bbc99bd7c811ccdfbfb78b2a48781a40e6ccbe7a7cfcdf625cb9c88b64df9";
+const EIGHT_BYTE_PADDING_MESSAGE: &str = "7a7a7a7a7a7a7a7a7a7a7a7a7a7a7a7a7a7a7a7a7a7a7a7a7a7a7a7a7a7a7a7a7a7a7a7a7a7a7a7a7a7a7a7a7a7a7a7a7a7a7a7a7a7a7a7a7a7a7a7a7a7a7a7a7a7a7a7a7a7a7a7a7a7a7a7a7a7a7a7a7a7a7a7a7a7a7a7a7a7a7a7a7a7a7a7a7a7a7a7a7a7a7a7a7a7a7a7a7a7a7a7a7a7a7a7a7a7a7a7a7a7a7a7a7a7a7a7a7a7a7a7a7a7a7a7a7a7a7a7a7a7a7a7a7a7a7a7a7a7a7a7a7a7a7a7a7a7a7a7a7a7a7a7a7a7a7a7a7a7a7a7a7a7a7a7a7a7a7a7a7a7a7a7a7a7a7a7a7a7a7a7a7a7a7a7a7a7a7a7a7a7a7a7a7a7a7a7a7a7a7a7a7a7a7a7a7a7a7a7a7a7a7a7a7a7a7a7a7a7a7a7a7a7a7a7a7a7a7a7a7a7a7a7a7a";
+const EMPTY_MESSAGE_CIPHER: &str = "0983263fefeb2ebf82c5b2fea97a9fa0af2cb7c6945f3d5f64fb42238ac5a4a551288908caa06d4b6a0c9a4ef69ee12577c77fa9c37fc63d3f7fadbdba499f53439f02ece49f21d29d07bf0ccb0fa0dc6bbbd48d0eda116fad635a8884eb2e9e2566a8359875cb1ad632acae961b16342546c65e0c5d279c8ab29dcbba12c2704621e5a235d3d9fed2397797a0b662d505dda1cc5de08e3e7378cfd02da0626202ed08ced68037fc6569928e9f053f691802a957d69fbe6e12b55a9e30e1b48f1e729d1aaf3a472074c62740dc8b2d19e27a865c04af71bb1e96c89ce0c8d41a6fbcf8282e872b6c56092534150ff1ea5d30b34852c6abc766e26fdf19fcb026";
+const EMPTY_MESSAGE_MESSAGE: &str = "";
+
+fn pkcs1() -> PKCS1v15 {
+    PKCS1v15::new(
+        RSA::from_components(
+            BigUint::from_be_bytes(&hex_to_u8(N)),
+            BigUint::from_u128(65537),
+            BigUint::from_be_bytes(&hex_to_u8(D)),
+        )
+        .unwrap(),
+    )
+    .unwrap()
+}
+
+fn check(cipher: &str, message: &str) {
+    assert_eq!(
+        u8_to_hex(pkcs1().decrypt(&hex_to_u8(cipher)).unwrap()),
+        message
+    );
+}
+
+#[test]
+fn pkcs1_decrypts_reference_ciphertexts() {
+    check(VALID_CIPHER, VALID_MESSAGE);
+    check(EIGHT_BYTE_PADDING_CIPHER, EIGHT_BYTE_PADDING_MESSAGE);
+    check(EMPTY_MESSAGE_CIPHER, EMPTY_MESSAGE_MESSAGE);
+    assert_eq!(hex_to_u8(VALID_MESSAGE), b"PKCS #1 v1.5");
+}
+
+#[test]
+fn pkcs1_implicit_rejection() {
+    check(WRONG_TYPE_CIPHER, WRONG_TYPE_MESSAGE);
+    check(SHORT_PADDING_CIPHER, SHORT_PADDING_MESSAGE);
+    check(NO_SEPARATOR_CIPHER, NO_SEPARATOR_MESSAGE);
+}
+
+#[test]
+fn pkcs1_round_trip() {
+    let pkcs1 = pkcs1();
+
+    for message in [
+        b"".to_vec(),
+        b"legacy".to_vec(),
+        vec![0; pkcs1.max_message_length()],
+    ] {
+        let cipher = pkcs1.encrypt(&message).unwrap();
+        assert_eq!(cipher.len(), 256);
+        assert_eq!(pkcs1.decrypt(&cipher).unwrap(), message);
+    }
+}
+
+#[test]
+fn pkcs1_rejects_long_messages() {
+    assert!(matches!(
+        pkcs1().encrypt(&[0; 246]),
+        Err(RSAError::MessageTooLong)
+    ));
+}
+
+#[test]
+fn pkcs1_rejects_malformed_ciphertexts() {
+    let pkcs1 = pkcs1();
+
+    assert!(matches!(
+        pkcs1.decrypt(&hex_to_u8(VALID_CIPHER)[1..]),
+        Err(RSAError::DecryptionError)
+    ));
+    assert!(matches!(
+        pkcs1.decrypt(&[0xff; 256]),
+        Err(RSAError::DecryptionError)
+    ));
+}
+
+#[test]
+fn pkcs1_generated_and_small_keys() {
+    let pkcs1 = PKCS1v15::new(RSA::generate(512).unwrap()).unwrap();
+    assert_eq!(
+        pkcs1.decrypt(&pkcs1.encrypt(b"512").unwrap()).unwrap(),
+        b"512"
+    );
+
+    assert!(matches!(
+        PKCS1v15::new(RSA::from_num(3233, 17, 413).unwrap()),
+        Err(RSAError::InvalidKeySize)
+    ));
+}
@@ -0,0 +1,120 @@
+use spectrum::cryptography::rsa::{RSAError, RSA};
+use spectrum::math::{generate_prime, is_probable_prime, BigUint};
+
+#[test]
+fn probable_primes() {
+    // 2^127 - 1 and 2^521 - 1 are Mersenne primes, 2^128 + 1 and 561 are not prime
+    let mersenne_127 = &(&BigUint::one() << 127) - &BigUint::one();
+    let mersenne_521 = &(&BigUint::one() << 521) - &BigUint::one();
+    let fermat_7 = &(&BigUint::one() << 128) + &BigUint::one();
+
+    assert!(is_probable_prime(&mersenne_127, 20));
+    assert!(is_probable_prime(&mersenne_521, 20));
+    assert!(!is_probable_prime(&fermat_7, 20));
+    assert!(!is_probable_prime(&BigUint::from_u128(561), 20));
+    assert!(is_probable_prime(&BigUint::from_u128(2), 20));
+    assert!(!is_probable_prime(&BigUint::one(), 20));
+    assert!(!is_probable_prime(&(&mersenne_127 * &mersenne_521), 20));
+}
+
+// Carmichael numbers fool the Fermat test but not Miller-Rabin
+#[test]
+fn carmichael_numbers() {
+    for n in [2465, 1105, 41041, 825265, 321197185, 3825123056546413051] {
+        assert!(!is_probable_prime(&BigUint::from_u128(n), 20));
+    }
+}
+
+#[test]
+fn generated_primes() {
+    for bits in [64, 100, 256] {
+        let prime = generate_prime(bits);
+        assert_eq!(prime.bits(), bits);
+        assert!(prime.bit(bits - 2));
+        assert!(is_probable_prime(&prime, 20));
+    }
+}
+
+#[test]
+fn generated_key() {
+    let e = BigUint::from_u128(65537);
+
+    for bits in [512, 1024] {
+        let rsa = RSA::generate(bits).unwrap();
+
+        assert_eq!(rsa.bits(), bits);
+        assert_eq!(rsa.e, e);
+        assert!(rsa.n.is_odd());
+
+        let message = BigUint::from_be_bytes(b"Hello, RSA");
+        assert_eq!(rsa.decrypt_integer(&rsa.encrypt_integer(&message)), message);
+    }
+}
+
+#[test]
+fn generated_keys_differ() {
+    assert_ne!(RSA::generate(512).unwrap().n, RSA::generate(512).unwrap().n);
+}
+
+#[test]
+fn rejects_small_keys() {
+    assert!(RSA::generate(256).is_err());
+}
+
+#[test]
+fn textbook_key() {
+    // p = 61, q = 53
+    let rsa = RSA::from_num(3233, 17, 413).unwrap();
+
+    let cipher = rsa.encrypt_integer(&BigUint::from_u128(65));
+    assert_eq!(cipher, BigUint::from_u128(2790));
+    assert_eq!(rsa.decrypt_integer(&cipher), BigUint::from_u128(65));
+}
+
+#[test]
+#[allow(deprecated)]
+fn deprecated_byte_api() {
+    let rsa = RSA::from_num(3233, 17, 413).unwrap();
+
+    let cipher = rsa.encrypt(vec![65, 66]);
+    assert_eq!(cipher[0], BigUint::from_u128(2790));
+    assert_eq!(rsa.decrypt(cipher), vec![65, 66]);
+}
+
+// The Montgomery ladder used for decryption needs an odd modulus larger than one
+#[test]
+fn rejects_invalid_modulus() {
+    for n in [0, 1, 3234] {
+        assert!(matches!(
+            RSA::from_num(n, 17, 413),
+            Err(RSAError::InvalidKeySize)
+        ));
+    }
+}
+
+fn check_generated_key(bits: usize) {
+    let rsa = RSA::generate(bits).unwrap();
+    assert_eq!(rsa.bits(), bits);
+
+    let message = BigUint::from_be_bytes(b"key size");
+    assert_eq!(rsa.decrypt_integer(&rsa.encrypt_integer(&message)), message);
+}
+
+#[test]
+fn generated_key_2048() {
+    check_generated_key(2048);
+}
+
+// Too slow without optimisations, run with
+// cargo test --release --test rsa -- --ignored
+#[test]
+#[ignore]
+fn generated_key_3072() {
+    check_generated_key(3072);
+}
+
+#[test]
+#[ignore]
+fn generated_key_4096() {
+    check_generated_key(4096);
+}
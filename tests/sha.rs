@@ -0,0 +1,290 @@
+use spectrum::cryptography::hash;
+use spectrum::cryptography::sha::{SHAType, SHA};
+use spectrum::cryptography::{HashingAlgorithm, StreamingHashingAlgorithm};
+use spectrum::format::u8_to_hex;
+
+// Test vectors from FIPS 180-4 / NIST CSRC examples
+#[test]
+fn sha256_empty() {
+    assert_eq!(
+        hash(&SHA::new(), String::new()),
+        "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855"
+    );
+}
+
+#[test]
+fn sha256_abc() {
+    assert_eq!(
+        hash(&SHA::new(), "abc".to_string()),
+        "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"
+    );
+}
+
+#[test]
+fn sha256_two_blocks() {
+    assert_eq!(
+        hash(
+            &SHA::new(),
+            "abcdbcdecdefdefgefghfghighijhijkijkljklmklmnlmnomnopnopq".to_string()
+        ),
+        "248d6a61d20638b8e5c026930c3e6039a33ce45964ff2167f6ecedd419db06c1"
+    );
+}
+
+#[test]
+fn sha256_896_bits() {
+    assert_eq!(
+        hash(
+            &SHA::new(),
+            "abcdefghbcdefghicdefghijdefghijkefghijklfghijklmghijklmnhijklmnoijklmnopjklmnopqklmnopqrlmnopqrsmnopqrstnopqrstu".to_string()
+        ),
+        "cf5b16a778af8380036ce59e7b0492370b249b11e8f07a51afac45037afee9d1"
+    );
+}
+
+#[test]
+fn sha256_million_a() {
+    assert_eq!(
+        u8_to_hex(SHA::new().hash(vec![b'a'; 1_000_000])),
+        "cdc76e5c9914fb9281a1c7e284d73e67f1809a48a497200e046d39ccc7112cd0"
+    );
+}
+
+#[test]
+fn sha256_padding_boundaries() {
+    assert_eq!(
+        u8_to_hex(SHA::new().hash(vec![b'a'; 55])),
+        "9f4390f8d30c2dd92ec9f095b65e2b9ae9b0a925a5258e241c9f1e910f734318"
+    );
+    assert_eq!(
+        u8_to_hex(SHA::new().hash(vec![b'a'; 56])),
+        "b35439a4ac6f0948b6d6f9e3c6af0f5f590ce20f1bde7090ef7970686ec6738a"
+    );
+    assert_eq!(
+        u8_to_hex(SHA::new().hash(vec![b'a'; 64])),
+        "ffe054fe7ae0cb6dc65c3af9b61d5209f439851db43d0ba5997337df154668eb"
+    );
+}
+
+#[test]
+fn sha256_streaming_matches_one_shot() {
+    let message: Vec<u8> = (0..1000).map(|i| (i % 251) as u8).collect();
+    let expected = SHA::new().hash(message.clone());
+
+    for size in [1, 7, 55, 56, 63, 64, 65, 128, 999] {
+        let mut sha = SHA::new();
+        for chunk in message.chunks(size) {
+            sha.update(chunk);
+        }
+        assert_eq!(sha.finalize(), expected);
+    }
+}
+
+#[test]
+fn sha256_finalize_resets() {
+    let mut sha = SHA::new();
+    sha.update(b"abc");
+    sha.finalize();
+    sha.update(b"abc");
+    assert_eq!(
+        u8_to_hex(sha.finalize()),
+        "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"
+    );
+
+    sha.update(b"garbage");
+    sha.reset();
+    assert_eq!(
+        u8_to_hex(sha.finalize()),
+        "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855"
+    );
+}
+
+const ABC_896: &str = "abcdefghbcdefghicdefghijdefghijkefghijklfghijklmghijklmnhijklmnoijklmnopjklmnopqklmnopqrlmnopqrsmnopqrstnopqrstu";
+
+fn digest(kind: SHAType, message: &[u8]) -> String {
+    u8_to_hex(SHA::from_type(kind).hash(message.to_vec()))
+}
+
+#[test]
+fn sha224_empty() {
+    assert_eq!(
+        digest(SHAType::SHA224, b""),
+        "d14a028c2a3a2bc9476102bb288234c415a2b01f828ea62ac5b3e42f"
+    );
+}
+
+#[test]
+fn sha224_abc() {
+    assert_eq!(
+        digest(SHAType::SHA224, b"abc"),
+        "23097d223405d8228642a477bda255b32aadbce4bda0b3f7e36c9da7"
+    );
+}
+
+#[test]
+fn sha224_two_blocks() {
+    assert_eq!(
+        digest(
+            SHAType::SHA224,
+            b"abcdbcdecdefdefgefghfghighijhijkijkljklmklmnlmnomnopnopq"
+        ),
+        "75388b16512776cc5dba5da1fd890150b0c6455cb4f58b1952522525"
+    );
+}
+
+#[test]
+fn sha224_million_a() {
+    assert_eq!(
+        digest(SHAType::SHA224, &vec![b'a'; 1_000_000]),
+        "20794655980c91d8bbb4c1ea97618a4bf03f42581948b2ee4ee7ad67"
+    );
+}
+
+#[test]
+fn sha384_empty() {
+    assert_eq!(
+        digest(SHAType::SHA384, b""),
+        "38b060a751ac96384cd9327eb1b1e36a21fdb71114be07434c0cc7bf63f6e1da274edebfe76f65fbd51ad2f14898b95b"
+    );
+}
+
+#[test]
+fn sha384_abc() {
+    assert_eq!(
+        digest(SHAType::SHA384, b"abc"),
+        "cb00753f45a35e8bb5a03d699ac65007272c32ab0eded1631a8b605a43ff5bed8086072ba1e7cc2358baeca134c825a7"
+    );
+}
+
+#[test]
+fn sha384_896_bits() {
+    assert_eq!(
+        digest(SHAType::SHA384, ABC_896.as_bytes()),
+        "09330c33f71147e83d192fc782cd1b4753111b173b3b05d22fa08086e3b0f712fcc7c71a557e2db966c3e9fa91746039"
+    );
+}
+
+#[test]
+fn sha384_million_a() {
+    assert_eq!(
+        digest(SHAType::SHA384, &vec![b'a'; 1_000_000]),
+        "9d0e1809716474cb086e834e310a4a1ced149e9c00f248527972cec5704c2a5b07b8b3dc38ecc4ebae97ddd87f3d8985"
+    );
+}
+
+#[test]
+fn sha512_empty() {
+    assert_eq!(
+        digest(SHAType::SHA512, b""),
+        "cf83e1357eefb8bdf1542850d66d8007d620e4050b5715dc83f4a921d36ce9ce47d0d13c5d85f2b0ff8318d2877eec2f63b931bd47417a81a538327af927da3e"
+    );
+}
+
+#[test]
+fn sha512_abc() {
+    assert_eq!(
+        digest(SHAType::SHA512, b"abc"),
+        "ddaf35a193617abacc417349ae20413112e6fa4e89a97ea20a9eeee64b55d39a2192992a274fc1a836ba3c23a3feebbd454d4423643ce80e2a9ac94fa54ca49f"
+    );
+}
+
+#[test]
+fn sha512_896_bits() {
+    assert_eq!(
+        digest(SHAType::SHA512, ABC_896.as_bytes()),
+        "8e959b75dae313da8cf4f72814fc143f8f7779c6eb9f7fa17299aeadb6889018501d289e4900f7e4331b99dec4b5433ac7d329eeb6dd26545e96e55b874be909"
+    );
+}
+
+#[test]
+fn sha512_million_a() {
+    assert_eq!(
+        digest(SHAType::SHA512, &vec![b'a'; 1_000_000]),
+        "e718483d0ce769644e2e42c7bc15b4638e1f98b13b2044285632a803afa973ebde0ff244877ea60a4cb0432ce577c31beb009c5c2c49aa2e4eadb217ad8cc09b"
+    );
+}
+
+#[test]
+fn sha512_224_empty() {
+    assert_eq!(
+        digest(SHAType::SHA512_224, b""),
+        "6ed0dd02806fa89e25de060c19d3ac86cabb87d6a0ddd05c333b84f4"
+    );
+}
+
+#[test]
+fn sha512_224_abc() {
+    assert_eq!(
+        digest(SHAType::SHA512_224, b"abc"),
+        "4634270f707b6a54daae7530460842e20e37ed265ceee9a43e8924aa"
+    );
+}
+
+#[test]
+fn sha512_224_896_bits() {
+    assert_eq!(
+        digest(SHAType::SHA512_224, ABC_896.as_bytes()),
+        "23fec5bb94d60b23308192640b0c453335d664734fe40e7268674af9"
+    );
+}
+
+#[test]
+fn sha512_224_million_a() {
+    assert_eq!(
+        digest(SHAType::SHA512_224, &vec![b'a'; 1_000_000]),
+        "37ab331d76f0d36de422bd0edeb22a28accd487b7a8453ae965dd287"
+    );
+}
+
+#[test]
+fn sha512_256_empty() {
+    assert_eq!(
+        digest(SHAType::SHA512_256, b""),
+        "c672b8d1ef56ed28ab87c3622c5114069bdd3ad7b8f9737498d0c01ecef0967a"
+    );
+}
+
+#[test]
+fn sha512_256_abc() {
+    assert_eq!(
+        digest(SHAType::SHA512_256, b"abc"),
+        "53048e2681941ef99b2e29b76b4c7dabe4c2d0c634fc6d46e0e2f13107e7af23"
+    );
+}
+
+#[test]
+fn sha512_256_896_bits() {
+    assert_eq!(
+        digest(SHAType::SHA512_256, ABC_896.as_bytes()),
+        "3928e184fb8690f840da3988121d31be65cb9d3ef83ee6146feac861e19b563a"
+    );
+}
+
+#[test]
+fn sha512_256_million_a() {
+    assert_eq!(
+        digest(SHAType::SHA512_256, &vec![b'a'; 1_000_000]),
+        "9a59a052930187a97038cae692f30708aa6491923ef5194394dc68d56c74fb21"
+    );
+}
+
+#[test]
+fn sha512_streaming_matches_one_shot() {
+    let message: Vec<u8> = (0..1000).map(|i| (i % 251) as u8).collect();
+
+    for kind in [
+        SHAType::SHA384,
+        SHAType::SHA512,
+        SHAType::SHA512_224,
+        SHAType::SHA512_256,
+    ] {
+        let expected = SHA::from_type(kind).hash(message.clone());
+        for size in [1, 111, 112, 127, 128, 129, 999] {
+            let mut sha = SHA::from_type(kind);
+            for chunk in message.chunks(size) {
+                sha.update(chunk);
+            }
+            assert_eq!(sha.finalize(), expected);
+        }
+    }
+}
@@ -0,0 +1,322 @@
+use spectrum::cryptography::sha3::{SHA3Type, SHAKEType, SHA3, SHAKE};
+use spectrum::cryptography::{HashingAlgorithm, StreamingHashingAlgorithm};
+use spectrum::format::u8_to_hex;
+
+// Test vectors from the NIST CSRC SHA-3 and SHAKE examples
+const ABC_896: &str = "abcdefghbcdefghicdefghijdefghijkefghijklfghijklmghijklmnhijklmnoijklmnopjklmnopqklmnopqrlmnopqrsmnopqrstnopqrstu";
+
+fn digest(kind: SHA3Type, message: &[u8]) -> String {
+    u8_to_hex(SHA3::from_type(kind).hash(message.to_vec()))
+}
+
+fn shake(kind: SHAKEType, output_size: usize, message: &[u8]) -> String {
+    u8_to_hex(SHAKE::from_type(kind, output_size).hash(message.to_vec()))
+}
+
+#[test]
+fn sha3_224_empty() {
+    assert_eq!(
+        digest(SHA3Type::SHA3_224, b""),
+        "6b4e03423667dbb73b6e15454f0eb1abd4597f9a1b078e3f5b5a6bc7"
+    );
+}
+
+#[test]
+fn sha3_224_abc() {
+    assert_eq!(
+        digest(SHA3Type::SHA3_224, b"abc"),
+        "e642824c3f8cf24ad09234ee7d3c766fc9a3a5168d0c94ad73b46fdf"
+    );
+}
+
+#[test]
+fn sha3_224_896_bits() {
+    assert_eq!(
+        digest(SHA3Type::SHA3_224, ABC_896.as_bytes()),
+        "543e6868e1666c1a643630df77367ae5a62a85070a51c14cbf665cbc"
+    );
+}
+
+#[test]
+fn sha3_224_million_a() {
+    assert_eq!(
+        digest(SHA3Type::SHA3_224, &vec![b'a'; 1_000_000]),
+        "d69335b93325192e516a912e6d19a15cb51c6ed5c15243e7a7fd653c"
+    );
+}
+
+#[test]
+fn sha3_224_200_a3() {
+    assert_eq!(
+        digest(SHA3Type::SHA3_224, &[0xa3; 200]),
+        "9376816aba503f72f96ce7eb65ac095deee3be4bf9bbc2a1cb7e11e0"
+    );
+}
+
+#[test]
+fn sha3_256_empty() {
+    assert_eq!(
+        digest(SHA3Type::SHA3_256, b""),
+        "a7ffc6f8bf1ed76651c14756a061d662f580ff4de43b49fa82d80a4b80f8434a"
+    );
+}
+
+#[test]
+fn sha3_256_abc() {
+    assert_eq!(
+        digest(SHA3Type::SHA3_256, b"abc"),
+        "3a985da74fe225b2045c172d6bd390bd855f086e3e9d525b46bfe24511431532"
+    );
+}
+
+#[test]
+fn sha3_256_896_bits() {
+    assert_eq!(
+        digest(SHA3Type::SHA3_256, ABC_896.as_bytes()),
+        "916f6061fe879741ca6469b43971dfdb28b1a32dc36cb3254e812be27aad1d18"
+    );
+}
+
+#[test]
+fn sha3_256_million_a() {
+    assert_eq!(
+        digest(SHA3Type::SHA3_256, &vec![b'a'; 1_000_000]),
+        "5c8875ae474a3634ba4fd55ec85bffd661f32aca75c6d699d0cdcb6c115891c1"
+    );
+}
+
+#[test]
+fn sha3_256_200_a3() {
+    assert_eq!(
+        digest(SHA3Type::SHA3_256, &[0xa3; 200]),
+        "79f38adec5c20307a98ef76e8324afbfd46cfd81b22e3973c65fa1bd9de31787"
+    );
+}
+
+#[test]
+fn sha3_384_empty() {
+    assert_eq!(
+        digest(SHA3Type::SHA3_384, b""),
+        "0c63a75b845e4f7d01107d852e4c2485c51a50aaaa94fc61995e71bbee983a2ac3713831264adb47fb6bd1e058d5f004"
+    );
+}
+
+#[test]
+fn sha3_384_abc() {
+    assert_eq!(
+        digest(SHA3Type::SHA3_384, b"abc"),
+        "ec01498288516fc926459f58e2c6ad8df9b473cb0fc08c2596da7cf0e49be4b298d88cea927ac7f539f1edf228376d25"
+    );
+}
+
+#[test]
+fn sha3_384_896_bits() {
+    assert_eq!(
+        digest(SHA3Type::SHA3_384, ABC_896.as_bytes()),
+        "79407d3b5916b59c3e30b09822974791c313fb9ecc849e406f23592d04f625dc8c709b98b43b3852b337216179aa7fc7"
+    );
+}
+
+#[test]
+fn sha3_384_million_a() {
+    assert_eq!(
+        digest(SHA3Type::SHA3_384, &vec![b'a'; 1_000_000]),
+        "eee9e24d78c1855337983451df97c8ad9eedf256c6334f8e948d252d5e0e76847aa0774ddb90a842190d2c558b4b8340"
+    );
+}
+
+#[test]
+fn sha3_384_200_a3() {
+    assert_eq!(
+        digest(SHA3Type::SHA3_384, &[0xa3; 200]),
+        "1881de2ca7e41ef95dc4732b8f5f002b189cc1e42b74168ed1732649ce1dbcdd76197a31fd55ee989f2d7050dd473e8f"
+    );
+}
+
+#[test]
+fn sha3_512_empty() {
+    assert_eq!(
+        digest(SHA3Type::SHA3_512, b""),
+        "a69f73cca23a9ac5c8b567dc185a756e97c982164fe25859e0d1dcc1475c80a615b2123af1f5f94c11e3e9402c3ac558f500199d95b6d3e301758586281dcd26"
+    );
+}
+
+#[test]
+fn sha3_512_abc() {
+    assert_eq!(
+        digest(SHA3Type::SHA3_512, b"abc"),
+        "b751850b1a57168a5693cd924b6b096e08f621827444f70d884f5d0240d2712e10e116e9192af3c91a7ec57647e3934057340b4cf408d5a56592f8274eec53f0"
+    );
+}
+
+#[test]
+fn sha3_512_896_bits() {
+    assert_eq!(
+        digest(SHA3Type::SHA3_512, ABC_896.as_bytes()),
+        "afebb2ef542e6579c50cad06d2e578f9f8dd6881d7dc824d26360feebf18a4fa73e3261122948efcfd492e74e82e2189ed0fb440d187f382270cb455f21dd185"
+    );
+}
+
+#[test]
+fn sha3_512_million_a() {
+    assert_eq!(
+        digest(SHA3Type::SHA3_512, &vec![b'a'; 1_000_000]),
+        "3c3a876da14034ab60627c077bb98f7e120a2a5370212dffb3385a18d4f38859ed311d0a9d5141ce9cc5c66ee689b266a8aa18ace8282a0e0db596c90b0a7b87"
+    );
+}
+
+#[test]
+fn sha3_512_200_a3() {
+    assert_eq!(
+        digest(SHA3Type::SHA3_512, &[0xa3; 200]),
+        "e76dfad22084a8b1467fcf2ffa58361bec7628edf5f3fdc0e4805dc48caeeca81b7c13c30adf52a3659584739a2df46be589c51ca1a4a8416df6545a1ce8ba00"
+    );
+}
+
+#[test]
+fn shake128_empty() {
+    assert_eq!(
+        shake(SHAKEType::SHAKE128, 32, b""),
+        "7f9c2ba4e88f827d616045507605853ed73b8093f6efbc88eb1a6eacfa66ef26"
+    );
+}
+
+#[test]
+fn shake128_abc() {
+    assert_eq!(
+        shake(SHAKEType::SHAKE128, 32, b"abc"),
+        "5881092dd818bf5cf8a3ddb793fbcba74097d5c526a6d35f97b83351940f2cc8"
+    );
+}
+
+#[test]
+fn shake128_896_bits() {
+    assert_eq!(
+        shake(SHAKEType::SHAKE128, 32, ABC_896.as_bytes()),
+        "7b6df6ff181173b6d7898d7ff63fb07b7c237daf471a5ae5602adbccef9ccf4b"
+    );
+}
+
+#[test]
+fn shake128_million_a() {
+    assert_eq!(
+        shake(SHAKEType::SHAKE128, 32, &vec![b'a'; 1_000_000]),
+        "9d222c79c4ff9d092cf6ca86143aa411e369973808ef97093255826c5572ef58"
+    );
+}
+
+#[test]
+fn shake128_200_a3() {
+    assert_eq!(
+        shake(SHAKEType::SHAKE128, 32, &[0xa3; 200]),
+        "131ab8d2b594946b9c81333f9bb6e0ce75c3b93104fa3469d3917457385da037"
+    );
+}
+
+#[test]
+fn shake256_empty() {
+    assert_eq!(
+        shake(SHAKEType::SHAKE256, 64, b""),
+        "46b9dd2b0ba88d13233b3feb743eeb243fcd52ea62b81b82b50c27646ed5762fd75dc4ddd8c0f200cb05019d67b592f6fc821c49479ab48640292eacb3b7c4be"
+    );
+}
+
+#[test]
+fn shake256_abc() {
+    assert_eq!(
+        shake(SHAKEType::SHAKE256, 64, b"abc"),
+        "483366601360a8771c6863080cc4114d8db44530f8f1e1ee4f94ea37e78b5739d5a15bef186a5386c75744c0527e1faa9f8726e462a12a4feb06bd8801e751e4"
+    );
+}
+
+#[test]
+fn shake256_896_bits() {
+    assert_eq!(
+        shake(SHAKEType::SHAKE256, 64, ABC_896.as_bytes()),
+        "98be04516c04cc73593fef3ed0352ea9f6443942d6950e29a372a681c3deaf4535423709b02843948684e029010badcc0acd8303fc85fdad3eabf4f78cae1656"
+    );
+}
+
+#[test]
+fn shake256_million_a() {
+    assert_eq!(
+        shake(SHAKEType::SHAKE256, 64, &vec![b'a'; 1_000_000]),
+        "3578a7a4ca9137569cdf76ed617d31bb994fca9c1bbf8b184013de8234dfd13a3fd124d4df76c0a539ee7dd2f6e1ec346124c815d9410e145eb561bcd97b18ab"
+    );
+}
+
+#[test]
+fn shake256_200_a3() {
+    assert_eq!(
+        shake(SHAKEType::SHAKE256, 64, &[0xa3; 200]),
+        "cd8a920ed141aa0407a22d59288652e9d9f1a7ee0c1e7c1ca699424da84a904d2d700caae7396ece96604440577da4f3aa22aeb8857f961c4cd8e06f0ae6610b"
+    );
+}
+
+#[test]
+fn shake128_squeeze_long_output() {
+    let mut reader = SHAKE::from_type(SHAKEType::SHAKE128, 32).finalize_xof();
+    let mut output = reader.squeeze(1);
+    output.append(&mut reader.squeeze(166));
+    output.append(&mut reader.squeeze(333));
+    assert_eq!(
+        u8_to_hex(output[468..].to_vec()),
+        "4e1300b9b2153d2041d205b443e41b45a653f2a5c4492c1add544512dda25298"
+    );
+}
+
+#[test]
+fn shake256_squeeze_long_output() {
+    let mut shake = SHAKE::from_type(SHAKEType::SHAKE256, 64);
+    shake.update(&[0xa3; 200]);
+    let output = shake.finalize_xof().squeeze(512);
+    assert_eq!(
+        u8_to_hex(output[480..].to_vec()),
+        "6a1a9d7846436e4dca5728b6f760eef0ca92bf0be5615e96959d767197a0beeb"
+    );
+}
+
+#[test]
+fn sha3_streaming_matches_one_shot() {
+    let message: Vec<u8> = (0..1000).map(|i| (i % 251) as u8).collect();
+
+    for kind in [
+        SHA3Type::SHA3_224,
+        SHA3Type::SHA3_256,
+        SHA3Type::SHA3_384,
+        SHA3Type::SHA3_512,
+    ] {
+        let expected = SHA3::from_type(kind).hash(message.clone());
+        for size in [1, 71, 72, 73, 136, 137, 999] {
+            let mut sha3 = SHA3::from_type(kind);
+            for chunk in message.chunks(size) {
+                sha3.update(chunk);
+            }
+            assert_eq!(sha3.finalize(), expected);
+        }
+    }
+}
+
+#[test]
+fn shake_finalize_resets() {
+    let mut shake = SHAKE::from_type(SHAKEType::SHAKE128, 32);
+    shake.update(b"garbage");
+    shake.finalize();
+    shake.update(b"abc");
+    assert_eq!(
+        u8_to_hex(shake.finalize()),
+        "5881092dd818bf5cf8a3ddb793fbcba74097d5c526a6d35f97b83351940f2cc8"
+    );
+}
+
+#[test]
+fn shake_reader_continues_the_digest() {
+    let mut shake = SHAKE::from_type(SHAKEType::SHAKE256, 64);
+    shake.update(b"abc");
+    let digest = shake.clone().finalize();
+
+    let mut reader = shake.finalize_xof();
+    let mut output = reader.squeeze(10);
+    output.append(&mut reader.squeeze(200));
+    assert_eq!(output[..64], digest[..]);
+}
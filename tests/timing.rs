@@ -0,0 +1,142 @@
+use std::hint::black_box;
+use std::time::Instant;
+
+use rand::Rng;
+use spectrum::cryptography::aes::{AESBackend, InitKey, AES};
+
+// Statistical timing test in the style of dudect (Reparaz, Balasch and Verbauwhede):
+// blocks from a fixed class and a random class are encrypted in random order and
+// Welch's t-test is run on the timings. |t| above 4.5 points at data-dependent timing.
+//
+// The harness must flag a known leaky control, an extra block encryption whenever the
+// first secret byte is zero, which runs by default. It does not catch cache timing:
+// where the 4 KiB of tables stay in L1 the Reference and TTable backends also stay
+// below the threshold, so passing is no evidence that a backend is constant time. That
+// guarantee for ConstantTime comes from its construction, no branches or table
+// lookups on key or state bytes, see the comment on sbox in aes.rs.
+//
+// Timing is noisy on shared machines and meaningless without optimisations, run the
+// backend tests with cargo test --release --test timing -- --ignored --nocapture
+
+const SAMPLES: usize = 200_000;
+const CONTROL_SAMPLES: usize = 5_000;
+const BATCH: usize = 8;
+const THRESHOLD: f64 = 4.5;
+
+struct Welch {
+    count: [f64; 2],
+    mean: [f64; 2],
+    m2: [f64; 2],
+}
+
+impl Welch {
+    fn new() -> Self {
+        Welch {
+            count: [0.0; 2],
+            mean: [0.0; 2],
+            m2: [0.0; 2],
+        }
+    }
+
+    fn push(&mut self, class: usize, value: f64) {
+        self.count[class] += 1.0;
+        let delta = value - self.mean[class];
+        self.mean[class] += delta / self.count[class];
+        self.m2[class] += delta * (value - self.mean[class]);
+    }
+
+    fn t(&self) -> f64 {
+        let variance = |class: usize| self.m2[class] / (self.count[class] - 1.0);
+        let error = (variance(0) / self.count[0] + variance(1) / self.count[1]).sqrt();
+        (self.mean[0] - self.mean[1]) / error
+    }
+}
+
+fn measure(samples: usize, mut operation: impl FnMut(&mut [u8; 16])) -> f64 {
+    let mut rng = rand::thread_rng();
+    let fixed = [0u8; 16];
+
+    // Inputs are prepared up front so that generating them does not disturb the timing
+    let mut inputs = Vec::with_capacity(samples);
+    for _ in 0..samples {
+        let class = rng.gen_range(0..2);
+        let mut blocks = [fixed; BATCH];
+        if class == 1 {
+            for block in blocks.iter_mut() {
+                rng.fill(block);
+            }
+        }
+        inputs.push((class, blocks));
+    }
+
+    let mut timings = Vec::with_capacity(samples);
+    for (class, mut blocks) in inputs {
+        let start = Instant::now();
+        for block in blocks.iter_mut() {
+            operation(black_box(block));
+        }
+        timings.push((class, start.elapsed().as_nanos() as f64));
+    }
+
+    // Drop the slowest samples, they are dominated by interrupts and scheduling
+    let mut sorted: Vec<f64> = timings.iter().map(|(_, time)| *time).collect();
+    sorted.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    let cutoff = sorted[sorted.len() * 9 / 10];
+
+    let mut welch = Welch::new();
+    for (class, time) in timings {
+        if time <= cutoff {
+            welch.push(class, time);
+        }
+    }
+
+    welch.t()
+}
+
+fn aes(backend: AESBackend) -> AES {
+    let key: Vec<u8> = (0..16).collect();
+    AES::with_backend(InitKey::from_slice(&key).unwrap(), backend)
+}
+
+// Branches on a secret byte, the harness has to flag this or its other results mean nothing
+fn leaky_control(aes: &AES, block: &mut [u8; 16]) {
+    if block[0] == 0 {
+        aes.encrypt_block(block);
+    }
+    aes.encrypt_block(block);
+}
+
+#[test]
+fn harness_flags_leaky_control() {
+    let aes = aes(AESBackend::ConstantTime);
+    let t = measure(CONTROL_SAMPLES, |block| leaky_control(&aes, block));
+    println!("leaky control: t = {t:.2}");
+    assert!(t.abs() > THRESHOLD, "t = {t:.2}");
+}
+
+#[test]
+#[ignore]
+fn constant_time_backend_timing_smoke_test() {
+    let aes = aes(AESBackend::ConstantTime);
+
+    let t = measure(SAMPLES, |block| leaky_control(&aes, block));
+    assert!(t.abs() > THRESHOLD, "leaky control not flagged, t = {t:.2}");
+
+    let t = measure(SAMPLES, |block| aes.encrypt_block(block));
+    println!("ConstantTime encrypt: t = {t:.2}");
+    assert!(t.abs() < THRESHOLD, "t = {t:.2}");
+
+    let t = measure(SAMPLES, |block| aes.decrypt_block(block));
+    println!("ConstantTime decrypt: t = {t:.2}");
+    assert!(t.abs() < THRESHOLD, "t = {t:.2}");
+}
+
+#[test]
+#[ignore]
+fn report_table_backends() {
+    for backend in [AESBackend::Reference, AESBackend::TTable] {
+        let aes = aes(backend);
+        let t = measure(SAMPLES, |block| aes.encrypt_block(block));
+        println!("{backend:?}: t = {t:.2}");
+    }
+}
@@ -0,0 +1,140 @@
+use spectrum::cryptography::aes::{AESError, XTS};
+use spectrum::format::{hex_to_u8, u8_to_hex};
+
+fn check(key: &str, sector: u128, plaintext: &str, expected: &str) {
+    let xts = XTS::new(&hex_to_u8(key)).unwrap();
+
+    assert_eq!(
+        u8_to_hex(xts.encrypt_sector(sector, &hex_to_u8(plaintext)).unwrap()),
+        expected
+    );
+    assert_eq!(
+        u8_to_hex(xts.decrypt_sector(sector, &hex_to_u8(expected)).unwrap()),
+        plaintext
+    );
+}
+
+// Test vectors from IEEE 1619-2007 appendix B. Vector 1 uses an all zero key, which
+// has equal halves and is rejected, see xts_rejects_equal_key_halves.
+
+#[test]
+fn xts_aes128_ieee1619_vector_2() {
+    check(
+        "1111111111111111111111111111111122222222222222222222222222222222",
+        0x3333333333,
+        "4444444444444444444444444444444444444444444444444444444444444444",
+        "c454185e6a16936e39334038acef838bfb186fff7480adc4289382ecd6d394f0",
+    );
+}
+
+#[test]
+fn xts_aes128_ieee1619_vector_3() {
+    check(
+        "fffefdfcfbfaf9f8f7f6f5f4f3f2f1f022222222222222222222222222222222",
+        0x3333333333,
+        "4444444444444444444444444444444444444444444444444444444444444444",
+        "af85336b597afc1a900b2eb21ec949d292df4c047e0b21532186a5971a227a89",
+    );
+}
+
+#[test]
+fn xts_aes128_ieee1619_vector_4() {
+    let plaintext: Vec<u8> = (0..=255).chain(0..=255).collect();
+    check(
+        "2718281828459045235360287471352631415926535897932384626433832795",
+        0,
+        &u8_to_hex(plaintext),
+        "27a7479befa1d476489f308cd4cfa6e2a96e4bbe3208ff25287dd3819616e89cc78cf7f5e543445f8333d8fa7f56000005279fa5d8b5e4ad40e736ddb4d35412328063fd2aab53e5ea1e0a9f332500a5df9487d07a5c92cc512c8866c7e860ce93fdf166a24912b422976146ae20ce846bb7dc9ba94a767aaef20c0d61ad02655ea92dc4c4e41a8952c651d33174be51a10c421110e6d81588ede82103a252d8a750e8768defffed9122810aaeb99f9172af82b604dc4b8e51bcb08235a6f4341332e4ca60482a4ba1a03b3e65008fc5da76b70bf1690db4eae29c5f1badd03c5ccf2a55d705ddcd86d449511ceb7ec30bf12b1fa35b913f9f747a8afd1b130e94bff94effd01a91735ca1726acd0b197c4e5b03393697e126826fb6bbde8ecc1e08298516e2c9ed03ff3c1b7860f6de76d4cecd94c8119855ef5297ca67e9f3e7ff72b1e99785ca0a7e7720c5b36dc6d72cac9574c8cbbc2f801e23e56fd344b07f22154beba0f08ce8891e643ed995c94d9a69c9f1b5f499027a78572aeebd74d20cc39881c213ee770b1010e4bea718846977ae119f7a023ab58cca0ad752afe656bb3c17256a9f6e9bf19fdd5a38fc82bbe872c5539edb609ef4f79c203ebb140f2e583cb2ad15b4aa5b655016a8449277dbd477ef2c8d6c017db738b18deb4a427d1923ce3ff262735779a418f20a282df920147beabe421ee5319d0568",
+    );
+}
+
+#[test]
+fn xts_aes256_ieee1619_vector_10() {
+    let plaintext: Vec<u8> = (0..=255).chain(0..=255).collect();
+    check(
+        "27182818284590452353602874713526624977572470936999595749669676273141592653589793238462643383279502884197169399375105820974944592",
+        0xff,
+        &u8_to_hex(plaintext),
+        "1c3b3a102f770386e4836c99e370cf9bea00803f5e482357a4ae12d414a3e63b5d31e276f8fe4a8d66b317f9ac683f44680a86ac35adfc3345befecb4bb188fd5776926c49a3095eb108fd1098baec70aaa66999a72a82f27d848b21d4a741b0c5cd4d5fff9dac89aeba122961d03a757123e9870f8acf1000020887891429ca2a3e7a7d7df7b10355165c8b9a6d0a7de8b062c4500dc4cd120c0f7418dae3d0b5781c34803fa75421c790dfe1de1834f280d7667b327f6c8cd7557e12ac3a0f93ec05c52e0493ef31a12d3d9260f79a289d6a379bc70c50841473d1a8cc81ec583e9645e07b8d9670655ba5bbcfecc6dc3966380ad8fecb17b6ba02469a020a84e18e8f84252070c13e9f1f289be54fbc481457778f616015e1327a02b140f1505eb309326d68378f8374595c849d84f4c333ec4423885143cb47bd71c5edae9be69a2ffeceb1bec9de244fbe15992b11b77c040f12bd8f6a975a44a0f90c29a9abc3d4d893927284c58754cce294529f8614dcd2aba991925fedc4ae74ffac6e333b93eb4aff0479da9a410e4450e0dd7ae4c6e2910900575da401fc07059f645e8b7e9bfdef33943054ff84011493c27b3429eaedb4ed5376441a77ed43851ad77f16f541dfd269d50d6a5f14fb0aab1cbb4c1550be97f7ab4066193c4caa773dad38014bd2092fa755c824bb5e54c4f36ffda9fcea70b9c6e693e148c151",
+    );
+}
+
+// Sectors that are not block aligned use ciphertext stealing (IEEE 1619 vectors 15 to 18)
+
+#[test]
+fn xts_aes128_ieee1619_vector_15() {
+    check(
+        "fffefdfcfbfaf9f8f7f6f5f4f3f2f1f0bfbebdbcbbbab9b8b7b6b5b4b3b2b1b0",
+        0x123456789a,
+        "000102030405060708090a0b0c0d0e0f10",
+        "6c1625db4671522d3d7599601de7ca09ed",
+    );
+}
+
+#[test]
+fn xts_aes128_ieee1619_vector_16() {
+    check(
+        "fffefdfcfbfaf9f8f7f6f5f4f3f2f1f0bfbebdbcbbbab9b8b7b6b5b4b3b2b1b0",
+        0x123456789a,
+        "000102030405060708090a0b0c0d0e0f1011",
+        "d069444b7a7e0cab09e24447d24deb1fedbf",
+    );
+}
+
+#[test]
+fn xts_aes128_ieee1619_vector_17() {
+    check(
+        "fffefdfcfbfaf9f8f7f6f5f4f3f2f1f0bfbebdbcbbbab9b8b7b6b5b4b3b2b1b0",
+        0x123456789a,
+        "000102030405060708090a0b0c0d0e0f101112",
+        "e5df1351c0544ba1350b3363cd8ef4beedbf9d",
+    );
+}
+
+#[test]
+fn xts_aes128_ieee1619_vector_18() {
+    check(
+        "fffefdfcfbfaf9f8f7f6f5f4f3f2f1f0bfbebdbcbbbab9b8b7b6b5b4b3b2b1b0",
+        0x123456789a,
+        "000102030405060708090a0b0c0d0e0f10111213",
+        "9d84c813f719aa2c7be3f66171c7c5c2edbf9dac",
+    );
+}
+
+#[test]
+fn xts_aes256_ciphertext_stealing() {
+    check(
+        "404142434445464748494a4b4c4d4e4f505152535455565758595a5b5c5d5e5f606162636465666768696a6b6c6d6e6f707172737475767778797a7b7c7d7e7f",
+        123456789,
+        "202122232425262728292a2b2c2d2e2f303132333435363738393a3b3c3d3e3f404142434445464748494a4b4c",
+        "beaec9ca28b13fe8bec61509ded2c3da8149e5b04e9b7c7b05cee5b925036bb9dd4845a537985e1d127f94f420",
+    );
+}
+
+#[test]
+fn xts_invalid_parameters() {
+    assert!(matches!(XTS::new(&[0; 48]), Err(AESError::InccorectSize)));
+
+    let xts = XTS::new(&hex_to_u8(
+        "1111111111111111111111111111111122222222222222222222222222222222",
+    ))
+    .unwrap();
+    assert!(matches!(
+        xts.encrypt_sector(0, &[0; 15]),
+        Err(AESError::InvalidLength)
+    ));
+    assert!(matches!(
+        xts.decrypt_sector(0, &[]),
+        Err(AESError::InvalidLength)
+    ));
+}
+
+#[test]
+fn xts_rejects_equal_key_halves() {
+    assert!(matches!(XTS::new(&[0; 32]), Err(AESError::InvalidKey)));
+    assert!(matches!(XTS::new(&[0x42; 64]), Err(AESError::InvalidKey)));
+
+    let mut key = [0x42; 64];
+    key[63] = 0x43;
+    assert!(XTS::new(&key).is_ok());
+}